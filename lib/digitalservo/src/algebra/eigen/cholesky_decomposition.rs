@@ -0,0 +1,242 @@
+use super::*;
+use num_traits::Float;
+use std::borrow::Borrow;
+use std::ops::{AddAssign, MulAssign};
+
+/* A = L * L^T */
+#[derive(Debug, Clone, Copy)]
+pub struct CholeskyMatrix<T, const N: usize> {
+    pub l: Matrix<T, N, N>,
+}
+
+/* A = L * D * L^T */
+#[derive(Debug, Clone, Copy)]
+pub struct LDLMatrix<T, const N: usize> {
+    pub l: Matrix<T, N, N>,
+    pub d: Vector<T, N>,
+}
+
+impl<T: Float + Default + AddAssign + MulAssign, const N: usize> Eigen<T, N> {
+    /* only the lower triangle of m is referred; returns None if m is not positive definite */
+    pub fn cholesky_decomposition<S: Borrow<Matrix<T, N, N>>>(m: S) -> Option<CholeskyMatrix<T, N>> {
+        let mut l: Matrix<T, N, N> = Matrix::new();
+        cholesky_factor(&m.borrow().data, &mut l.data)?;
+        Some(CholeskyMatrix { l })
+    }
+
+    /* only the lower triangle of m is referred; returns None if a zero pivot appears */
+    pub fn ldl_decomposition<S: Borrow<Matrix<T, N, N>>>(m: S) -> Option<LDLMatrix<T, N>> {
+        let m: &Matrix<T, N, N> = m.borrow();
+        let mut l: Matrix<T, N, N> = Matrix::diag(T::one());
+        let mut d: Vector<T, N> = Vector::new();
+
+        for j in 0..N {
+            let mut dj: T = m[j][j];
+            for k in 0..j {
+                dj = dj - l[j][k] * l[j][k] * d[k];
+            }
+            if dj == T::zero() || !dj.is_finite() {
+                return None;
+            }
+            d[j] = dj;
+
+            for i in (j + 1)..N {
+                let mut x: T = m[i][j];
+                for k in 0..j {
+                    x = x - l[i][k] * l[j][k] * d[k];
+                }
+                l[i][j] = x / dj;
+            }
+        }
+
+        Some(LDLMatrix { l, d })
+    }
+}
+
+impl<T: Float + Default + AddAssign + MulAssign, const N: usize> CholeskyMatrix<T, N> {
+    pub fn solve<S: Borrow<Vector<T, N>>>(&self, b: S) -> Vector<T, N> {
+        let mut y: Vector<T, N> = Vector::new();
+        let mut x: Vector<T, N> = Vector::new();
+        forward_substitution(&self.l.data, &b.borrow().data, &mut y.data);
+        backward_substitution_transposed(&self.l.data, &y.data, &mut x.data);
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        let mut ret: Matrix<T, N, N> = Matrix::new();
+        for j in 0..N {
+            let mut e: Vector<T, N> = Vector::new();
+            e[j] = T::one();
+            let x: Vector<T, N> = self.solve(e);
+            for i in 0..N {
+                ret[i][j] = x[i];
+            }
+        }
+        ret
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut ret: T = T::one();
+        for i in 0..N {
+            ret *= self.l[i][i] * self.l[i][i];
+        }
+        ret
+    }
+
+    /* factor of A + x * x^T */
+    pub fn update<S: Borrow<Vector<T, N>>>(mut self, x: S) -> Self {
+        let mut x: Vector<T, N> = *x.borrow();
+        for k in 0..N {
+            let r: T = self.l[k][k].hypot(x[k]);
+            let c: T = r / self.l[k][k];
+            let s: T = x[k] / self.l[k][k];
+            self.l[k][k] = r;
+            for i in (k + 1)..N {
+                self.l[i][k] = (self.l[i][k] + s * x[i]) / c;
+                x[i] = c * x[i] - s * self.l[i][k];
+            }
+        }
+        self
+    }
+
+    /* factor of A - x * x^T; returns None if the result is not positive definite */
+    pub fn downdate<S: Borrow<Vector<T, N>>>(mut self, x: S) -> Option<Self> {
+        let mut x: Vector<T, N> = *x.borrow();
+        for k in 0..N {
+            let r2: T = (self.l[k][k] - x[k]) * (self.l[k][k] + x[k]);
+            if r2 <= T::zero() || r2.is_nan() {
+                return None;
+            }
+            let r: T = r2.sqrt();
+            let c: T = r / self.l[k][k];
+            let s: T = x[k] / self.l[k][k];
+            self.l[k][k] = r;
+            for i in (k + 1)..N {
+                self.l[i][k] = (self.l[i][k] - s * x[i]) / c;
+                x[i] = c * x[i] - s * self.l[i][k];
+            }
+        }
+        Some(self)
+    }
+}
+
+impl<T: Float + Default + AddAssign + MulAssign, const N: usize> LDLMatrix<T, N> {
+    pub fn solve<S: Borrow<Vector<T, N>>>(&self, b: S) -> Vector<T, N> {
+        let mut y: Vector<T, N> = Vector::new();
+        let mut x: Vector<T, N> = Vector::new();
+        forward_substitution(&self.l.data, &b.borrow().data, &mut y.data);
+        for i in 0..N {
+            y[i] = y[i] / self.d[i];
+        }
+        backward_substitution_transposed(&self.l.data, &y.data, &mut x.data);
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        let mut ret: Matrix<T, N, N> = Matrix::new();
+        for j in 0..N {
+            let mut e: Vector<T, N> = Vector::new();
+            e[j] = T::one();
+            let x: Vector<T, N> = self.solve(e);
+            for i in 0..N {
+                ret[i][j] = x[i];
+            }
+        }
+        ret
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut ret: T = T::one();
+        for i in 0..N {
+            ret *= self.d[i];
+        }
+        ret
+    }
+
+    pub fn is_positive_definite(&self) -> bool {
+        self.d.data.iter().all(|&x| x > T::zero())
+    }
+
+    /* factor of A + alpha * x * x^T */
+    fn rank_one_modification(mut self, x: &Vector<T, N>, alpha: T) -> Option<Self> {
+        let mut x: Vector<T, N> = *x;
+        let mut alpha: T = alpha;
+        for j in 0..N {
+            let p: T = x[j];
+            let d: T = self.d[j] + alpha * p * p;
+            if d == T::zero() || !d.is_finite() {
+                return None;
+            }
+            let beta: T = p * alpha / d;
+            alpha = self.d[j] * alpha / d;
+            self.d[j] = d;
+            for i in (j + 1)..N {
+                x[i] = x[i] - p * self.l[i][j];
+                self.l[i][j] += beta * x[i];
+            }
+        }
+        Some(self)
+    }
+
+    /* factor of A + x * x^T; returns None if a zero pivot appears */
+    pub fn update<S: Borrow<Vector<T, N>>>(self, x: S) -> Option<Self> {
+        self.rank_one_modification(x.borrow(), T::one())
+    }
+
+    /* factor of A - x * x^T; returns None if a zero pivot appears */
+    pub fn downdate<S: Borrow<Vector<T, N>>>(self, x: S) -> Option<Self> {
+        self.rank_one_modification(x.borrow(), -T::one())
+    }
+}
+
+/* M = L * L^T for the matrix given by its rows, only the lower triangle of m is referred; */
+/* l must be zero-initialized, returns None if m is not positive definite */
+pub(crate) fn cholesky_factor<T, R, W>(m: &[R], l: &mut [W]) -> Option<()>
+where
+    T: Float,
+    R: AsRef<[T]>,
+    W: AsRef<[T]> + AsMut<[T]>,
+{
+    for j in 0..m.len() {
+        let mut d: T = m[j].as_ref()[j];
+        for k in 0..j {
+            d = d - l[j].as_ref()[k] * l[j].as_ref()[k];
+        }
+        if d <= T::zero() || d.is_nan() {
+            return None;
+        }
+        l[j].as_mut()[j] = d.sqrt();
+
+        for i in (j + 1)..m.len() {
+            let mut x: T = m[i].as_ref()[j];
+            for k in 0..j {
+                x = x - l[i].as_ref()[k] * l[j].as_ref()[k];
+            }
+            l[i].as_mut()[j] = x / l[j].as_ref()[j];
+        }
+    }
+    Some(())
+}
+
+/* solve L * y = b for the lower triangular L given by its rows */
+pub(crate) fn forward_substitution<T: Float, R: AsRef<[T]>>(l: &[R], b: &[T], y: &mut [T]) {
+    for i in 0..b.len() {
+        let row: &[T] = l[i].as_ref();
+        let mut x: T = b[i];
+        for k in 0..i {
+            x = x - row[k] * y[k];
+        }
+        y[i] = x / row[i];
+    }
+}
+
+/* solve L^T * x = y for the lower triangular L given by its rows */
+pub(crate) fn backward_substitution_transposed<T: Float, R: AsRef<[T]>>(l: &[R], y: &[T], x: &mut [T]) {
+    for i in (0..y.len()).rev() {
+        let mut v: T = y[i];
+        for k in (i + 1)..y.len() {
+            v = v - l[k].as_ref()[i] * x[k];
+        }
+        x[i] = v / l[i].as_ref()[i];
+    }
+}
@@ -0,0 +1,430 @@
+use super::*;
+use num_complex::Complex;
+use num_traits::Float;
+use std::borrow::Borrow;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexEigen<T, const N: usize> {
+    pub value: [Complex<T>; N],
+    pub vector: [[Complex<T>; N]; N],
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> Eigen<T, N> {
+    /* similarity transform to the upper Hessenberg form by Householder reflections */
+    pub fn hessenberg_reduction<S: Borrow<Matrix<T, N, N>>>(m: S) -> Matrix<T, N, N> {
+        let mut h: Matrix<T, N, N> = *m.borrow();
+        if N < 3 {
+            return h;
+        }
+
+        for k in 0..(N - 2) {
+            let mut alpha: T = T::zero();
+            for i in (k + 1)..N {
+                alpha += h[i][k] * h[i][k];
+            }
+            alpha = alpha.sqrt();
+            if alpha == T::zero() {
+                continue;
+            }
+            if h[k + 1][k] > T::zero() {
+                alpha = -alpha;
+            }
+
+            let mut v: [T; N] = [T::zero(); N];
+            for i in (k + 1)..N {
+                v[i] = h[i][k];
+            }
+            v[k + 1] -= alpha;
+            let v_norm: T = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+            if v_norm == T::zero() {
+                continue;
+            }
+            let tau: T = T::from(2.0).unwrap() / v_norm;
+
+            //H <- P * H
+            for j in 0..N {
+                let mut x: T = T::zero();
+                for i in (k + 1)..N {
+                    x += v[i] * h[i][j];
+                }
+                x *= tau;
+                for i in (k + 1)..N {
+                    h[i][j] -= x * v[i];
+                }
+            }
+
+            //H <- H * P
+            for i in 0..N {
+                let mut x: T = T::zero();
+                for j in (k + 1)..N {
+                    x += h[i][j] * v[j];
+                }
+                x *= tau;
+                for j in (k + 1)..N {
+                    h[i][j] -= x * v[j];
+                }
+            }
+
+            for i in (k + 2)..N {
+                h[i][k] = T::zero();
+            }
+        }
+
+        h
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> ComplexEigen<T, N> {
+    /* eigenvalues of a real matrix by the shifted Francis QR method; None if it does not converge */
+    pub fn francis_qr_eigenvalue<S: Borrow<Matrix<T, N, N>>>(m: S) -> Option<[Complex<T>; N]> {
+        let a: Matrix<T, N, N> = balance(m.borrow());
+        let a: Matrix<T, N, N> = Eigen::hessenberg_reduction(a);
+        hessenberg_qr(a)
+    }
+
+    /* eigenvalues and unit eigenvectors (by inverse iteration) of a real matrix */
+    pub fn francis_qr_method<S: Borrow<Matrix<T, N, N>>>(m: S) -> Option<Self> {
+        let m: &Matrix<T, N, N> = m.borrow();
+        let value: [Complex<T>; N] = Self::francis_qr_eigenvalue(m)?;
+
+        let mut vector: [[Complex<T>; N]; N] = [[Complex::new(T::zero(), T::zero()); N]; N];
+        for i in 0..N {
+            vector[i] = inverse_iteration(m, value[i]);
+        }
+
+        Some(Self { value, vector })
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> Matrix<T, N, N> {
+    pub fn eigenvalues(&self) -> Option<[Complex<T>; N]> {
+        ComplexEigen::francis_qr_eigenvalue(self)
+    }
+
+    pub fn spectral_radius(&self) -> Option<T> {
+        let value: [Complex<T>; N] = self.eigenvalues()?;
+        Some(value.iter().fold(T::zero(), |acc, x| acc.max(x.norm())))
+    }
+}
+
+/* diagonal similarity transform which equalizes the row and column norms */
+fn balance<T: Float + Default + MulAssign, const N: usize>(m: &Matrix<T, N, N>) -> Matrix<T, N, N> {
+    let radix: T = T::from(2.0).unwrap();
+    let radix_sq: T = radix * radix;
+    let mut a: Matrix<T, N, N> = *m;
+
+    let mut done: bool = false;
+    while !done {
+        done = true;
+        for i in 0..N {
+            let mut r: T = T::zero();
+            let mut c: T = T::zero();
+            for j in 0..N {
+                if j != i {
+                    c = c + a[j][i].abs();
+                    r = r + a[i][j].abs();
+                }
+            }
+            if c == T::zero() || r == T::zero() {
+                continue;
+            }
+
+            let s: T = c + r;
+            let mut f: T = T::one();
+            let mut g: T = r / radix;
+            while c < g {
+                f *= radix;
+                c *= radix_sq;
+            }
+            g = r * radix;
+            while c > g {
+                f = f / radix;
+                c = c / radix_sq;
+            }
+
+            if (c + r) / f < T::from(0.95).unwrap() * s {
+                done = false;
+                let g: T = T::one() / f;
+                for j in 0..N {
+                    a[i][j] *= g;
+                }
+                for j in 0..N {
+                    a[j][i] *= f;
+                }
+            }
+        }
+    }
+
+    a
+}
+
+/* double-shift QR iteration on an upper Hessenberg matrix */
+fn hessenberg_qr<T, const N: usize>(mut a: Matrix<T, N, N>) -> Option<[Complex<T>; N]>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    const MAX_ITERATION: usize = 60;
+    let eps: T = T::epsilon();
+    let mut value: [Complex<T>; N] = [Complex::new(T::zero(), T::zero()); N];
+
+    let mut anorm: T = T::zero();
+    for i in 0..N {
+        for j in i.saturating_sub(1)..N {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    //number of the eigenvalues not found yet
+    let mut n_active: usize = N;
+    let mut t: T = T::zero();
+
+    while n_active > 0 {
+        let nn: usize = n_active - 1;
+        let mut its: usize = 0;
+
+        loop {
+            //look for a single small subdiagonal element
+            let mut l: usize = nn;
+            while l > 0 {
+                let mut s: T = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == T::zero() {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() <= eps * s {
+                    a[l][l - 1] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x: T = a[nn][nn];
+            if l == nn {
+                //one root found
+                value[nn] = Complex::new(x + t, T::zero());
+                n_active -= 1;
+                break;
+            }
+
+            let mut y: T = a[nn - 1][nn - 1];
+            let mut w: T = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                //two roots found
+                let p: T = T::from(0.5).unwrap() * (y - x);
+                let q: T = p * p + w;
+                let z: T = q.abs().sqrt();
+                x += t;
+                if q >= T::zero() {
+                    let z: T = p + z.copysign(p);
+                    value[nn - 1] = Complex::new(x + z, T::zero());
+                    value[nn] = if z != T::zero() {
+                        Complex::new(x - w / z, T::zero())
+                    } else {
+                        Complex::new(x + z, T::zero())
+                    };
+                } else {
+                    value[nn] = Complex::new(x + p, -z);
+                    value[nn - 1] = Complex::new(x + p, z);
+                }
+                n_active -= 2;
+                break;
+            }
+
+            if its == MAX_ITERATION {
+                return None;
+            }
+
+            //exceptional shift
+            if its == 10 || its == 20 {
+                t += x;
+                for i in 0..(nn + 1) {
+                    a[i][i] -= x;
+                }
+                let s: T = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+            its += 1;
+
+            //look for two consecutive small subdiagonal elements
+            let mut m: usize = nn - 2;
+            let (mut p, mut q, mut r): (T, T, T);
+            loop {
+                let z: T = a[m][m];
+                let rr: T = x - z;
+                let ss: T = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s: T = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u: T = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v: T = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u <= eps * v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m..(nn - 1) {
+                a[i + 2][i] = T::zero();
+                if i != m {
+                    a[i + 2][i - 1] = T::zero();
+                }
+            }
+
+            //double QR step on rows l to nn and columns m to nn
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k + 1 != nn { a[k + 2][k - 1] } else { T::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != T::zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+
+                let s: T = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == T::zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z: T = r / s;
+                q = q / p;
+                r = r / p;
+
+                //row modification
+                for j in k..(nn + 1) {
+                    let mut pp: T = a[k][j] + q * a[k + 1][j];
+                    if k + 1 != nn {
+                        pp += r * a[k + 2][j];
+                        a[k + 2][j] -= pp * z;
+                    }
+                    a[k + 1][j] -= pp * y;
+                    a[k][j] -= pp * x;
+                }
+
+                //column modification
+                let mmin: usize = if nn < k + 3 { nn } else { k + 3 };
+                for i in l..(mmin + 1) {
+                    let mut pp: T = x * a[i][k] + y * a[i][k + 1];
+                    if k + 1 != nn {
+                        pp += z * a[i][k + 2];
+                        a[i][k + 2] -= pp * r;
+                    }
+                    a[i][k + 1] -= pp * q;
+                    a[i][k] -= pp;
+                }
+            }
+        }
+    }
+
+    Some(value)
+}
+
+/* eigenvector for the eigenvalue lambda, normalized so that the largest element is real */
+fn inverse_iteration<T, const N: usize>(m: &Matrix<T, N, N>, lambda: Complex<T>) -> [Complex<T>; N]
+where
+    T: Float + Default + AddAssign + MulAssign,
+{
+    const ITERATION: usize = 3;
+    let zero: Complex<T> = Complex::new(T::zero(), T::zero());
+
+    let mut anorm: T = T::zero();
+    for i in 0..N {
+        for j in 0..N {
+            anorm = anorm.max(m[i][j].abs());
+        }
+    }
+    let tiny: T = T::epsilon() * anorm.max(lambda.norm()).max(T::min_positive_value());
+
+    //LU decomposition of (m - lambda * I) with partial pivoting
+    let mut lu: [[Complex<T>; N]; N] = [[zero; N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            lu[i][j] = Complex::new(m[i][j], T::zero());
+        }
+        lu[i][i] = lu[i][i] - lambda;
+    }
+    let mut perm: [usize; N] = [0; N];
+    for k in 0..N {
+        let mut pivot: usize = k;
+        for i in (k + 1)..N {
+            if lu[i][k].norm() > lu[pivot][k].norm() {
+                pivot = i;
+            }
+        }
+        perm[k] = pivot;
+        lu.swap(k, pivot);
+        if lu[k][k].norm() < tiny {
+            lu[k][k] = Complex::new(tiny, T::zero());
+        }
+        let row_k: [Complex<T>; N] = lu[k];
+        for row in lu.iter_mut().skip(k + 1) {
+            let f: Complex<T> = row[k] / row_k[k];
+            row[k] = f;
+            for j in (k + 1)..N {
+                row[j] = row[j] - f * row_k[j];
+            }
+        }
+    }
+
+    let mut x: [Complex<T>; N] = [Complex::new(T::one(), T::zero()); N];
+    for _ in 0..ITERATION {
+        for (k, &pk) in perm.iter().enumerate() {
+            x.swap(k, pk);
+        }
+        for i in 0..N {
+            for k in 0..i {
+                let y: Complex<T> = lu[i][k] * x[k];
+                x[i] = x[i] - y;
+            }
+        }
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                let y: Complex<T> = lu[i][k] * x[k];
+                x[i] = x[i] - y;
+            }
+            x[i] = x[i] / lu[i][i];
+        }
+
+        let mut x_max: Complex<T> = zero;
+        for xi in x.iter() {
+            if xi.norm() > x_max.norm() {
+                x_max = *xi;
+            }
+        }
+        if x_max == zero {
+            break;
+        }
+        for xi in x.iter_mut() {
+            *xi = *xi / x_max;
+        }
+    }
+
+    let norm: T = x.iter().fold(T::zero(), |acc, xi| acc + xi.norm_sqr()).sqrt();
+    if norm > T::zero() {
+        for xi in x.iter_mut() {
+            *xi = *xi / norm;
+        }
+    }
+    x
+}
@@ -1,8 +1,11 @@
 use super::*;
 
+pub mod cholesky_decomposition;
+pub mod general_eigenvalue;
 pub mod jacobi_eigenvalue;
 pub mod lu_decomposition;
 pub mod qr_decomposition;
+pub mod singular_value_decomposition;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Eigen<T, const N: usize> {
@@ -67,3 +67,131 @@ impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> Eig
         Self { value, vector }
     }
 }
+
+/* A * P = Q * R */
+#[derive(Debug, Clone, Copy)]
+pub struct QRPMatrix<T, const ROWS: usize, const COLS: usize> {
+    pub q: Matrix<T, ROWS, ROWS>,
+    pub r: Matrix<T, ROWS, COLS>,
+    pub p: Matrix<T, COLS, COLS>,
+    pub rank: usize,
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, COLS>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    /* Householder reflection with column pivoting */
+    pub fn householder_qr_decomposition(&self) -> QRPMatrix<T, ROWS, COLS> {
+        let mut q: Matrix<T, ROWS, ROWS> = Matrix::diag(T::one());
+        let mut r: Matrix<T, ROWS, COLS> = *self;
+        let mut p: Matrix<T, COLS, COLS> = Matrix::diag(T::one());
+
+        for k in 0..std::cmp::min(ROWS, COLS) {
+            //pivoting: bring the column with the largest remaining norm to k
+            let mut pivot: usize = k;
+            let mut norm_max: T = -T::one();
+            for j in k..COLS {
+                let mut norm: T = T::zero();
+                for i in k..ROWS {
+                    norm += r[i][j] * r[i][j];
+                }
+                if norm > norm_max {
+                    norm_max = norm;
+                    pivot = j;
+                }
+            }
+            if pivot != k {
+                for i in 0..ROWS {
+                    r[i].swap(k, pivot);
+                }
+                for i in 0..COLS {
+                    p[i].swap(k, pivot);
+                }
+            }
+
+            //householder vector
+            let mut alpha: T = norm_max.sqrt();
+            if alpha == T::zero() {
+                break;
+            }
+            if r[k][k] > T::zero() {
+                alpha = -alpha;
+            }
+            let mut v: [T; ROWS] = [T::zero(); ROWS];
+            for i in k..ROWS {
+                v[i] = r[i][k];
+            }
+            v[k] -= alpha;
+            let v_norm: T = v[k..].iter().fold(T::zero(), |acc, &x| acc + x * x);
+            if v_norm == T::zero() {
+                continue;
+            }
+            let tau: T = T::from(2.0).unwrap() / v_norm;
+
+            //R <- H * R
+            for j in k..COLS {
+                let mut x: T = T::zero();
+                for i in k..ROWS {
+                    x += v[i] * r[i][j];
+                }
+                x *= tau;
+                for i in k..ROWS {
+                    r[i][j] -= x * v[i];
+                }
+            }
+            for i in (k + 1)..ROWS {
+                r[i][k] = T::zero();
+            }
+
+            //Q <- Q * H
+            for i in 0..ROWS {
+                let mut x: T = T::zero();
+                for j in k..ROWS {
+                    x += q[i][j] * v[j];
+                }
+                x *= tau;
+                for j in k..ROWS {
+                    q[i][j] -= x * v[j];
+                }
+            }
+        }
+
+        let n: usize = std::cmp::min(ROWS, COLS);
+        let rank: usize = if n == 0 {
+            0
+        } else {
+            let tolerance: T =
+                T::from(std::cmp::max(ROWS, COLS)).unwrap() * T::epsilon() * r[0][0].abs();
+            (0..n).filter(|&i| r[i][i].abs() > tolerance).count()
+        };
+
+        QRPMatrix { q, r, p, rank }
+    }
+
+    pub fn least_squares<S: Borrow<Vector<T, ROWS>>>(&self, b: S) -> Vector<T, COLS> {
+        self.householder_qr_decomposition().least_squares(b)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> QRPMatrix<T, ROWS, COLS>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    /* basic solution which minimizes |A * x - b| (the free variables are set to zero) */
+    pub fn least_squares<S: Borrow<Vector<T, ROWS>>>(&self, b: S) -> Vector<T, COLS> {
+        let b: &Vector<T, ROWS> = b.borrow();
+        let qtb: Vector<T, ROWS> = self.q.transpose() * b;
+
+        let mut z: Vector<T, COLS> = Vector::new();
+        for i in (0..self.rank).rev() {
+            let mut x: T = qtb[i];
+            for j in (i + 1)..self.rank {
+                x -= self.r[i][j] * z[j];
+            }
+            z[i] = x / self.r[i][i];
+        }
+
+        self.p * z
+    }
+}
@@ -0,0 +1,273 @@
+use super::*;
+use num_traits::Float;
+use std::ops::{AddAssign, MulAssign};
+
+/* A = U * Sigma * V^T */
+#[derive(Debug, Clone, Copy)]
+pub struct SVDMatrix<T, const ROWS: usize, const COLS: usize> {
+    pub u: Matrix<T, ROWS, ROWS>,
+    pub sigma: Matrix<T, ROWS, COLS>,
+    pub v: Matrix<T, COLS, COLS>,
+}
+
+impl<T: Float + Default + AddAssign + MulAssign, const ROWS: usize, const COLS: usize>
+    Matrix<T, ROWS, COLS>
+{
+    pub fn singular_value_decomposition(&self) -> SVDMatrix<T, ROWS, COLS> {
+        let mut u: Matrix<T, ROWS, ROWS> = Matrix::new();
+        let mut sigma: Matrix<T, ROWS, COLS> = Matrix::new();
+        let mut v: Matrix<T, COLS, COLS> = Matrix::new();
+
+        if ROWS >= COLS {
+            //A * V = W, where the columns of W are orthogonal
+            let (w, vr, norm) = one_sided_jacobi(self);
+            let rank: usize = numerical_rank(&norm);
+            for j in 0..COLS {
+                sigma[j][j] = norm[j];
+                for i in 0..COLS {
+                    v[i][j] = vr[i][j];
+                }
+            }
+            for j in 0..rank {
+                for i in 0..ROWS {
+                    u[i][j] = w[i][j] / norm[j];
+                }
+            }
+            complete_orthonormal_basis(&mut u, rank);
+        } else {
+            //A^T * U = W, where the columns of W are orthogonal
+            let (w, ur, norm) = one_sided_jacobi(&self.transpose());
+            let rank: usize = numerical_rank(&norm);
+            for j in 0..ROWS {
+                sigma[j][j] = norm[j];
+                for i in 0..ROWS {
+                    u[i][j] = ur[i][j];
+                }
+            }
+            for j in 0..rank {
+                for i in 0..COLS {
+                    v[i][j] = w[i][j] / norm[j];
+                }
+            }
+            complete_orthonormal_basis(&mut v, rank);
+        }
+
+        SVDMatrix { u, sigma, v }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.singular_value_decomposition().rank()
+    }
+
+    pub fn condition_number(&self) -> T {
+        self.singular_value_decomposition().condition_number()
+    }
+
+    pub fn null_space(&self) -> Vec<Vector<T, COLS>> {
+        self.singular_value_decomposition().null_space()
+    }
+
+    pub fn pseudo_inverse(&self) -> Matrix<T, COLS, ROWS> {
+        self.singular_value_decomposition().pseudo_inverse()
+    }
+}
+
+impl<T: Float + Default + AddAssign + MulAssign, const ROWS: usize, const COLS: usize>
+    SVDMatrix<T, ROWS, COLS>
+{
+    pub fn singular_values(&self) -> Vec<T> {
+        (0..std::cmp::min(ROWS, COLS))
+            .map(|i| self.sigma[i][i])
+            .collect()
+    }
+
+    /* singular values below this threshold are regarded as zero */
+    pub fn default_tolerance(&self) -> T {
+        let dim: T = T::from(std::cmp::max(ROWS, COLS)).unwrap();
+        dim * T::epsilon() * self.sigma[0][0]
+    }
+
+    pub fn rank_with_tolerance(&self, tolerance: T) -> usize {
+        self.singular_values()
+            .iter()
+            .filter(|&&s| s > tolerance)
+            .count()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank_with_tolerance(self.default_tolerance())
+    }
+
+    pub fn condition_number(&self) -> T {
+        let n: usize = std::cmp::min(ROWS, COLS);
+        if n == 0 || self.sigma[n - 1][n - 1] == T::zero() {
+            return T::infinity();
+        }
+        self.sigma[0][0] / self.sigma[n - 1][n - 1]
+    }
+
+    pub fn null_space_with_tolerance(&self, tolerance: T) -> Vec<Vector<T, COLS>> {
+        let rank: usize = self.rank_with_tolerance(tolerance);
+        (rank..COLS)
+            .map(|j| {
+                let mut x: Vector<T, COLS> = Vector::new();
+                for i in 0..COLS {
+                    x[i] = self.v[i][j];
+                }
+                x
+            })
+            .collect()
+    }
+
+    pub fn null_space(&self) -> Vec<Vector<T, COLS>> {
+        self.null_space_with_tolerance(self.default_tolerance())
+    }
+
+    pub fn pseudo_inverse_with_tolerance(&self, tolerance: T) -> Matrix<T, COLS, ROWS> {
+        let mut ret: Matrix<T, COLS, ROWS> = Matrix::new();
+        for k in 0..std::cmp::min(ROWS, COLS) {
+            let s: T = self.sigma[k][k];
+            if s <= tolerance {
+                break;
+            }
+            for i in 0..COLS {
+                let vs: T = self.v[i][k] / s;
+                for j in 0..ROWS {
+                    ret[i][j] += vs * self.u[j][k];
+                }
+            }
+        }
+        ret
+    }
+
+    pub fn pseudo_inverse(&self) -> Matrix<T, COLS, ROWS> {
+        self.pseudo_inverse_with_tolerance(self.default_tolerance())
+    }
+}
+
+/* Hestenes method: returns (A * V, V, column norms) with columns sorted in descending order */
+fn one_sided_jacobi<T, const ROWS: usize, const COLS: usize>(
+    m: &Matrix<T, ROWS, COLS>,
+) -> (Matrix<T, ROWS, COLS>, Matrix<T, COLS, COLS>, [T; COLS])
+where
+    T: Float + Default + AddAssign + MulAssign,
+{
+    const MAX_SWEEP: usize = 100;
+    let mut w: Matrix<T, ROWS, COLS> = *m;
+    let mut v: Matrix<T, COLS, COLS> = Matrix::diag(T::one());
+
+    for _ in 0..MAX_SWEEP {
+        let mut rotated: bool = false;
+        for p in 0..COLS {
+            for q in (p + 1)..COLS {
+                let mut alpha: T = T::zero();
+                let mut beta: T = T::zero();
+                let mut gamma: T = T::zero();
+                for i in 0..ROWS {
+                    alpha += w[i][p] * w[i][p];
+                    beta += w[i][q] * w[i][q];
+                    gamma += w[i][p] * w[i][q];
+                }
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta: T = (beta - alpha) / (gamma + gamma);
+                let t: T = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c: T = T::one() / (T::one() + t * t).sqrt();
+                let s: T = c * t;
+
+                for i in 0..ROWS {
+                    let (wp, wq) = (w[i][p], w[i][q]);
+                    w[i][p] = c * wp - s * wq;
+                    w[i][q] = s * wp + c * wq;
+                }
+                for i in 0..COLS {
+                    let (vp, vq) = (v[i][p], v[i][q]);
+                    v[i][p] = c * vp - s * vq;
+                    v[i][q] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut norm: [T; COLS] = [T::zero(); COLS];
+    for (j, n) in norm.iter_mut().enumerate() {
+        for i in 0..ROWS {
+            *n += w[i][j] * w[i][j];
+        }
+        *n = n.sqrt();
+    }
+
+    //selection sort on column norms
+    for j in 0..COLS {
+        let mut k: usize = j;
+        for l in (j + 1)..COLS {
+            if norm[l] > norm[k] {
+                k = l;
+            }
+        }
+        if k != j {
+            norm.swap(j, k);
+            for i in 0..ROWS {
+                w[i].swap(j, k);
+            }
+            for i in 0..COLS {
+                v[i].swap(j, k);
+            }
+        }
+    }
+
+    (w, v, norm)
+}
+
+fn numerical_rank<T: Float, const N: usize>(sorted_norm: &[T; N]) -> usize {
+    if N == 0 {
+        return 0;
+    }
+    let tolerance: T = T::from(N).unwrap() * T::epsilon() * sorted_norm[0];
+    sorted_norm
+        .iter()
+        .filter(|&&s| s > tolerance && s > T::zero())
+        .count()
+}
+
+/* fill columns [k, N) so that the columns of m form an orthonormal basis */
+fn complete_orthonormal_basis<T, const N: usize>(m: &mut Matrix<T, N, N>, k: usize)
+where
+    T: Float + Default + AddAssign + MulAssign,
+{
+    let mut filled: usize = k;
+    for e in 0..N {
+        if filled == N {
+            break;
+        }
+        let mut x: [T; N] = [T::zero(); N];
+        x[e] = T::one();
+
+        //modified Gram-Schmidt, applied twice for numerical orthogonality
+        for _ in 0..2 {
+            for j in 0..filled {
+                let mut r: T = T::zero();
+                for i in 0..N {
+                    r += m[i][j] * x[i];
+                }
+                for i in 0..N {
+                    x[i] = x[i] - r * m[i][j];
+                }
+            }
+        }
+
+        let norm: T = x.iter().fold(T::zero(), |acc, &xi| acc + xi * xi).sqrt();
+        if norm > T::from(0.5).unwrap() / T::from(N).unwrap().sqrt() {
+            for i in 0..N {
+                m[i][filled] = x[i] / norm;
+            }
+            filled += 1;
+        }
+    }
+}
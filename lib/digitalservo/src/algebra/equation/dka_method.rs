@@ -10,9 +10,10 @@ where [(); N - 1]:
     let coefficients: &[T; N] = coefficients.borrow();
     let mut z: [Complex<T>; N - 1] = [Complex::default(); N - 1];
 
-    let c1n: T = coefficients[1] / T::from(N - 1).unwrap();
+    /* centroid of the roots, around which the initial values are placed */
+    let c1n: T = -coefficients[1] / T::from(N - 1).unwrap();
     if N == 2 {
-        z[0] = Complex {re: -c1n / coefficients[0], im: T::zero()};
+        z[0] = Complex {re: c1n / coefficients[0], im: T::zero()};
         return Some(z);
     }
 
@@ -34,12 +35,13 @@ where [(); N - 1]:
     /* Aberth method */
     let mut c_radius: [T; N] = [T::one(); N];
     for i in 1..N {
-        c_radius[i] = -c_horner[i];
+        c_radius[i] = -(c_horner[i] / coefficients[0]).abs();
     }
 
-    let mut r_0: T = T::zero();
-    for i in 0..N {
-        let x: T = (coefficients[i] / coefficients[0]).abs().powf(T::from(1.0 / ((i as f64) + 1.0)).unwrap());
+    /* Cauchy's bound, from which Newton's method decreases to the positive root */
+    let mut r_0: T = T::one();
+    for c in c_radius.iter().skip(1) {
+        let x: T = T::one() + c.abs();
         r_0 = if r_0 > x { r_0 } else { x };
     }
 
@@ -51,28 +53,28 @@ where [(); N - 1]:
         z[i] = Complex{re: c1n / coefficients[0], im: T::zero()} + r * phase.exp();
     }
 
-    /* Durand-Kerner method */
+    /* Durand-Kerner method, converged when every residual is at the rounding level of the evaluation; */
+    /* roots far smaller than Cauchy's bound are judged by their absolute size */
     const MAX_ITERATION: usize = 10000;
-    let mut iteration: usize = 0;
-    let mut h_sum: T;
+    let tolerance: T = T::epsilon() * T::from(100 * N).unwrap();
+    let bound: T = coefficients.iter().skip(1).fold(T::one(), |acc, c| acc.max(T::one() + (*c / coefficients[0]).abs()));
+    let floor: T = T::epsilon().sqrt() * bound;
+
+    for _ in 0..MAX_ITERATION {
+        let converged: bool = z.iter().all(|x| {
+            mapping_h(coefficients, *x).norm() <= tolerance * mapping_scale(coefficients, x.norm().max(floor))
+        });
+        if converged { return Some(z); }
 
-    loop {
-        h_sum = T::zero();
-        
         for i in 0..(N - 1) {
             let h: Complex<T> = mapping_h(coefficients, z[i]);
             let dh: Complex<T> = mapping_dh(z, i);
-            if (dh == Complex{re: T::zero(), im: T::zero()}) { break; }
+            if (dh == Complex{re: T::zero(), im: T::zero()}) { return None; }
             z[i] = z[i] - h / dh;
-            h_sum = h_sum + h.norm();
         }
-
-        iteration += 1;
-
-        if h_sum < T::from(1e-15).unwrap() || iteration == MAX_ITERATION { break; }
     }
 
-    Some(z)
+    None
 }
 
 fn mapping_h<T: Float, S: Borrow<[T; N]>, const N: usize>(coefficients: S, x: Complex<T>) -> Complex<T> {
@@ -84,6 +86,12 @@ fn mapping_h<T: Float, S: Borrow<[T; N]>, const N: usize>(coefficients: S, x: Co
     ret
 }
 
+/* sum of |c_i| * |x|^(N - 1 - i), which bounds the rounding error of mapping_h */
+fn mapping_scale<T: Float, S: Borrow<[T; N]>, const N: usize>(coefficients: S, x: T) -> T {
+    let coefficients = coefficients.borrow();
+    coefficients.iter().fold(T::zero(), |acc, c| acc * x + c.abs())
+}
+
 fn mapping_dh<T: Float, S: Borrow<[Complex<T>; N]>, const N: usize>(z: S, j: usize) -> Complex<T> {
     let z = z.borrow();
     let z_j: Complex<T> = z[j];
@@ -0,0 +1,86 @@
+use super::EquationError;
+use crate::algebra::eigen::qr_decomposition::QRPMatrix;
+use crate::algebra::*;
+use num_traits::Float;
+use std::borrow::Borrow;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+/* solve A * X + X * A^T + Q = 0; fails if lambda_i + lambda_j = 0 for some eigenvalues of A */
+pub fn continuous_lyapunov_equation<T, S1, S2, const N: usize>(a: S1, q: S2) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    S1: Borrow<Matrix<T, N, N>>,
+    S2: Borrow<Matrix<T, N, N>>,
+    [(); N * N]:,
+{
+    let a: &Matrix<T, N, N> = a.borrow();
+
+    //row-major vectorization: X[i][j] -> x[i * N + j]
+    let mut k: Matrix<T, { N * N }, { N * N }> = Matrix::new();
+    for i in 0..N {
+        for j in 0..N {
+            for l in 0..N {
+                k[i * N + j][l * N + j] += a[i][l];
+                k[i * N + j][i * N + l] += a[j][l];
+            }
+        }
+    }
+
+    solve_vectorized(&k, q.borrow())
+}
+
+/* solve A * X * A^T - X + Q = 0; fails if lambda_i * lambda_j = 1 for some eigenvalues of A */
+pub fn discrete_lyapunov_equation<T, S1, S2, const N: usize>(a: S1, q: S2) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    S1: Borrow<Matrix<T, N, N>>,
+    S2: Borrow<Matrix<T, N, N>>,
+    [(); N * N]:,
+{
+    let a: &Matrix<T, N, N> = a.borrow();
+
+    let mut k: Matrix<T, { N * N }, { N * N }> = Matrix::new();
+    for i in 0..N {
+        for j in 0..N {
+            for l in 0..N {
+                for m in 0..N {
+                    k[i * N + j][l * N + m] = a[i][l] * a[j][m];
+                }
+            }
+            k[i * N + j][i * N + j] -= T::one();
+        }
+    }
+
+    solve_vectorized(&k, q.borrow())
+}
+
+/* solve K * vec(X) = -vec(Q) by the Householder QR */
+fn solve_vectorized<T, const N: usize>(k: &Matrix<T, { N * N }, { N * N }>, q: &Matrix<T, N, N>) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N * N]:,
+{
+    let mut vec_q: Vector<T, { N * N }> = Vector::new();
+    for i in 0..N {
+        for j in 0..N {
+            vec_q[i * N + j] = -q[i][j];
+        }
+    }
+
+    let qr: QRPMatrix<T, { N * N }, { N * N }> = k.householder_qr_decomposition();
+    if qr.rank < N * N {
+        return Err(EquationError::SingularEquation);
+    }
+    let vec_x: Vector<T, { N * N }> = qr.least_squares(vec_q);
+
+    let mut x: Matrix<T, N, N> = Matrix::new();
+    for i in 0..N {
+        for j in 0..N {
+            if !vec_x[i * N + j].is_finite() {
+                return Err(EquationError::SingularEquation);
+            }
+            x[i][j] = vec_x[i * N + j];
+        }
+    }
+    Ok(x)
+}
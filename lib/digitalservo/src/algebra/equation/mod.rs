@@ -1,2 +1,34 @@
 pub mod dka_method;
+pub mod lyapunov_equation;
+pub mod riccati_equation;
 pub mod vieta_formula;
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EquationError {
+    /* the vectorized linear system has no unique solution */
+    SingularEquation,
+    /* the input weight R (or R + B^T * X * B) is not invertible */
+    SingularWeight,
+    /* the Hamiltonian matrix has eigenvalues on the imaginary axis */
+    EigenvaluesOnBoundary,
+    /* the solution does not stabilize the closed loop */
+    NoStabilizingSolution,
+    NotConverged,
+}
+
+impl fmt::Display for EquationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message: &str = match self {
+            EquationError::SingularEquation => "equation error: no unique solution exists.",
+            EquationError::SingularWeight => "equation error: singular input weight.",
+            EquationError::EigenvaluesOnBoundary => "equation error: eigenvalues on the stability boundary.",
+            EquationError::NoStabilizingSolution => "equation error: no stabilizing solution exists.",
+            EquationError::NotConverged => "equation error: iteration did not converge.",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for EquationError {}
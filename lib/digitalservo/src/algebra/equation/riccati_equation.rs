@@ -0,0 +1,114 @@
+use super::EquationError;
+use crate::algebra::*;
+use num_traits::Float;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+const MAX_ITERATION: usize = 100;
+
+/* solve A^T * X + X * A - X * B * R^-1 * B^T * X + Q = 0 for the stabilizing X (structured doubling algorithm after the Cayley transform) */
+pub fn continuous_algebraic_riccati_equation<T, const N: usize, const M: usize>(
+    a: &Matrix<T, N, N>,
+    b: &Matrix<T, N, M>,
+    q: &Matrix<T, N, N>,
+    r: &Matrix<T, M, M>,
+) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let r_inv: Matrix<T, M, M> = r.inverse().ok_or(EquationError::SingularWeight)?;
+    let g: Matrix<T, N, N> = *b * r_inv * b.transpose();
+    let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+
+    //the stable eigenvalues of the Hamiltonian are mapped into the unit circle
+    let gamma: T = a.frobenius_norm().max((g.frobenius_norm() * q.frobenius_norm()).sqrt()).max(T::one());
+    let a_gamma_inv: Matrix<T, N, N> = (*a - identity * gamma).inverse().ok_or(EquationError::EigenvaluesOnBoundary)?;
+    let w_gamma: Matrix<T, N, N> = (*a - identity * gamma).transpose() + *q * a_gamma_inv * g;
+    let v_gamma: Matrix<T, N, N> = (*a - identity * gamma) + g * a_gamma_inv.transpose() * *q;
+    let w_gamma_inv: Matrix<T, N, N> = w_gamma.inverse().ok_or(EquationError::EigenvaluesOnBoundary)?;
+    let v_gamma_inv: Matrix<T, N, N> = v_gamma.inverse().ok_or(EquationError::EigenvaluesOnBoundary)?;
+
+    let gamma2: T = gamma + gamma;
+    let a0: Matrix<T, N, N> = identity + v_gamma_inv * gamma2;
+    let g0: Matrix<T, N, N> = a_gamma_inv * g * w_gamma_inv * gamma2;
+    let h0: Matrix<T, N, N> = w_gamma_inv * *q * a_gamma_inv * gamma2;
+
+    let x: Matrix<T, N, N> = structured_doubling(a0, g0, h0)?;
+
+    //the closed loop A - G * X must be stable
+    let closed_loop: Matrix<T, N, N> = *a - g * x;
+    let poles: [num_complex::Complex<T>; N] = closed_loop.eigenvalues().ok_or(EquationError::NotConverged)?;
+    let boundary: T = T::epsilon().sqrt() * gamma;
+    if poles.iter().any(|p| p.re.abs() <= boundary) {
+        return Err(EquationError::EigenvaluesOnBoundary);
+    }
+    if !poles.iter().all(|p| p.re < T::zero()) {
+        return Err(EquationError::NoStabilizingSolution);
+    }
+
+    Ok(x)
+}
+
+/* solve A^T * X * A - X - A^T * X * B * (R + B^T * X * B)^-1 * B^T * X * A + Q = 0 for the stabilizing X (structured doubling algorithm) */
+pub fn discrete_algebraic_riccati_equation<T, const N: usize, const M: usize>(
+    a: &Matrix<T, N, N>,
+    b: &Matrix<T, N, M>,
+    q: &Matrix<T, N, N>,
+    r: &Matrix<T, M, M>,
+) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let r_inv: Matrix<T, M, M> = r.inverse().ok_or(EquationError::SingularWeight)?;
+    let g: Matrix<T, N, N> = *b * r_inv * b.transpose();
+    let x: Matrix<T, N, N> = structured_doubling(*a, g, *q)?;
+
+    //the closed loop A - B * K must be stable, K = (R + B^T * X * B)^-1 * B^T * X * A
+    let btx: Matrix<T, M, N> = b.transpose() * x;
+    let gain_denom: Matrix<T, M, M> = (*r + btx * *b).inverse().ok_or(EquationError::SingularWeight)?;
+    let closed_loop: Matrix<T, N, N> = *a - *b * gain_denom * btx * *a;
+    let radius: T = closed_loop.spectral_radius().ok_or(EquationError::NotConverged)?;
+    if radius >= T::one() {
+        return Err(EquationError::NoStabilizingSolution);
+    }
+
+    Ok(x)
+}
+
+/* A_k -> 0 and H_k -> X for X = H + A^T * X * (I + G * X)^-1 * A */
+fn structured_doubling<T, const N: usize>(
+    a: Matrix<T, N, N>,
+    g: Matrix<T, N, N>,
+    h: Matrix<T, N, N>,
+) -> Result<Matrix<T, N, N>, EquationError>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+    let mut ak: Matrix<T, N, N> = a;
+    let mut gk: Matrix<T, N, N> = g.symmetrize();
+    let mut hk: Matrix<T, N, N> = h.symmetrize();
+
+    //the convergence is quadratic, so one more step is taken after the change gets small
+    let tolerance: T = T::epsilon().sqrt();
+    let mut converged: bool = false;
+    for _ in 0..MAX_ITERATION {
+        let w_inv: Matrix<T, N, N> = (identity + gk * hk).inverse().ok_or(EquationError::NoStabilizingSolution)?;
+        let aw: Matrix<T, N, N> = ak * w_inv;
+        let a_next: Matrix<T, N, N> = aw * ak;
+        let g_next: Matrix<T, N, N> = gk + aw * gk * ak.transpose();
+        let h_next: Matrix<T, N, N> = hk + ak.transpose() * hk * w_inv * ak;
+
+        if !h_next.frobenius_norm().is_finite() {
+            return Err(EquationError::NoStabilizingSolution);
+        }
+        let change: T = (h_next - hk).frobenius_norm();
+        ak = a_next;
+        gk = g_next.symmetrize();
+        hk = h_next.symmetrize();
+        if converged {
+            return Ok(hk);
+        }
+        converged = change <= tolerance * hk.frobenius_norm();
+    }
+    Err(EquationError::NotConverged)
+}
@@ -15,6 +15,12 @@ where
         ret
     }
 
+    /* (A + A^T) / 2, which removes the asymmetry accumulated by rounding */
+    pub fn symmetrize(&self) -> Self {
+        let t_05: T = T::from(0.5).unwrap();
+        (*self + self.transpose()) * t_05
+    }
+
     pub fn determinant(&self) -> T {
         let qr_matrix = Eigen::gram_schmidt_process(self);
         let mut ret: T = T::one();
@@ -24,12 +30,63 @@ where
         ret
     }
 
+    /* scaling and squaring with the diagonal Pade approximation */
     pub fn exp(self) -> Self {
-        pub const P: usize = 1000;
+        const PADE_ORDER: usize = 6;
+        let identity: Matrix<T, N, N> = Matrix::<T, N, N>::diag(T::one());
+
+        //scale so that the infinity norm is less than 1/2
+        let norm: T = self.infinity_norm();
+        let mut squaring: i32 = 0;
+        if norm > T::from(0.5).unwrap() {
+            squaring = norm.log2().floor().to_i32().unwrap() + 2;
+        }
+        let a: Matrix<T, N, N> = self / T::from(2.0).unwrap().powi(squaring);
+
+        let mut c: T = T::from(0.5).unwrap();
+        let mut x: Matrix<T, N, N> = a;
+        let mut numer: Matrix<T, N, N> = identity + a * c;
+        let mut denom: Matrix<T, N, N> = identity - a * c;
+        for k in 2..(PADE_ORDER + 1) {
+            c = c * T::from(PADE_ORDER - k + 1).unwrap()
+                / T::from(k * (2 * PADE_ORDER - k + 1)).unwrap();
+            x = a * x;
+            if k % 2 == 0 {
+                denom += x * c;
+            } else {
+                denom -= x * c;
+            }
+            numer += x * c;
+        }
+
+        let mut ret: Matrix<T, N, N> = denom.inverse().unwrap() * numer;
+        for _ in 0..squaring {
+            ret = ret * ret;
+        }
+        ret
+    }
+
+    /* det(sI - A) in descending powers of s */
+    pub fn characteristic_polynomial(&self) -> [T; N + 1]
+    where
+        [(); N + 1]:,
+    {
+        let mut ret: [T; N + 1] = [T::one(); N + 1];
+        ret[1..].copy_from_slice(&self.characteristic_coefficients());
+        ret
+    }
+
+    /* det(sI - A) in descending powers of s except the leading 1 (Faddeev-LeVerrier algorithm) */
+    pub fn characteristic_coefficients(&self) -> [T; N] {
         let identity: Matrix<T, N, N> = Matrix::<T, N, N>::diag(T::one());
-        let mut ret: Matrix<T, N, N> = identity + self / T::from(P).unwrap();
-        for i in (1..P).rev() {
-            ret = identity + self / T::from(i).unwrap() * ret;
+        let mut ret: [T; N] = [T::zero(); N];
+        let mut previous: T = T::one();
+
+        let mut m: Matrix<T, N, N> = Matrix::new();
+        for (k, coefficient) in ret.iter_mut().enumerate() {
+            m = *self * m + identity * previous;
+            *coefficient = -(*self * m).trace() / T::from(k + 1).unwrap();
+            previous = *coefficient;
         }
         ret
     }
@@ -49,6 +106,21 @@ where
         ret.sqrt()
     }
 
+    /* maximum absolute row sum */
+    pub fn infinity_norm(&self) -> T {
+        let mut ret = T::zero();
+        for i in 0..ROWS {
+            let mut x: T = T::zero();
+            for j in 0..COLS {
+                x += self[i][j].abs();
+            }
+            if x > ret {
+                ret = x;
+            }
+        }
+        ret
+    }
+
     pub fn max_norm(&self) -> T {
         let mut ret = T::zero();
         for i in 0..ROWS {
@@ -12,6 +12,7 @@ pub mod plant;
 pub mod signal;
 pub mod state_space;
 pub mod system_identification;
+pub mod transfer_function;
 
 //Re-export
 pub use data_storage::*;
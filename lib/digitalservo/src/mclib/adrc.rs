@@ -0,0 +1,87 @@
+use std::ops::AddAssign;
+
+use crate::combinatorics::*;
+use crate::observer::extended_state_observer::ExtendedStateObserver;
+use num_traits::Float;
+
+/* u = (r^(N) + sum k_i * (r^(i) - z_i) - f) / b0, where f is estimated by the extended state observer */
+#[derive(Debug, Copy, Clone)]
+pub struct ADRCController<T, const N: usize>
+where
+    [(); N + 1]:,
+{
+    eso: ExtendedStateObserver<T, N>,
+    k: [T; N],
+    u: T,
+    limit: Option<T>,
+}
+
+impl<T: Float + Default + AddAssign, const N: usize> ADRCController<T, N>
+where
+    [(); N + 1]:,
+{
+    /* closed-loop poles at -controller_bandwidth and observer poles at -observer_bandwidth */
+    pub fn new(ts: T, b0: T, controller_bandwidth: T, observer_bandwidth: T) -> Self {
+        //k_i = C(N, i) * bandwidth^(N - i), e.g. [kp, kd] = [wc^2, 2 * wc] for N = 2
+        let mut k: [T; N] = [T::zero(); N];
+        for (i, ki) in k.iter_mut().enumerate() {
+            *ki = T::from(combination(N, i)).unwrap() * controller_bandwidth.powi((N - i) as i32);
+        }
+        Self {
+            eso: ExtendedStateObserver::new(ts, b0, observer_bandwidth),
+            k,
+            u: T::zero(),
+            limit: None,
+        }
+    }
+
+    /* state feedback gain on [y, y', ..., y^(N-1)], e.g. [kp, kd] for N = 2 */
+    #[must_use]
+    pub fn set_gain(mut self, k: &[T; N]) -> Self {
+        self.k = *k;
+        self
+    }
+
+    /* nonlinear ESO with fal(e, alpha^i, delta) */
+    #[must_use]
+    pub fn set_fal(mut self, alpha: T, delta: T) -> Self {
+        self.eso = self.eso.set_fal(alpha, delta);
+        self
+    }
+
+    /* the saturated input is fed to the observer */
+    pub fn set_limit(&mut self, limit: T) {
+        self.limit = Some(limit);
+    }
+
+    pub fn gain(&self) -> [T; N] {
+        self.k
+    }
+
+    pub fn observer(&self) -> &ExtendedStateObserver<T, N> {
+        &self.eso
+    }
+
+    pub fn calc(&mut self, reference: T, response: T) -> T {
+        let mut r: [T; N + 1] = [T::zero(); N + 1];
+        r[0] = reference;
+        self.calc_trajectory(&r, response)
+    }
+
+    /* reference = [r, r', ..., r^(N)] */
+    pub fn calc_trajectory(&mut self, reference: &[T; N + 1], response: T) -> T {
+        let z: [T; N + 1] = self.eso.update(self.u, response);
+
+        let mut u0: T = reference[N];
+        for i in 0..N {
+            u0 += self.k[i] * (reference[i] - z[i]);
+        }
+        let mut u: T = (u0 - z[N]) / self.eso.b0();
+
+        if let Some(limit) = self.limit {
+            u = u.max(-limit).min(limit);
+        }
+        self.u = u;
+        u
+    }
+}
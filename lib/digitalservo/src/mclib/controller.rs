@@ -70,3 +70,169 @@ impl<T: num_traits::Float + Default + AddAssign> PDController<T> {
         self.kp * self.err_p + self.kd * self.err_d
     }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AntiWindup<T> {
+    None,
+    /* stop integrating while the output is saturated in the direction of the error */
+    Clamping,
+    /* feed (u_sat - u) / tt back to the integrator */
+    BackCalculation(T),
+}
+
+/* u = kp * (b * r - y) + ki * integral of (r - y) + kd * s / (1 + s / g) * (c * r - y) */
+#[derive(Debug, Copy, Clone)]
+pub struct PIDController<T> {
+    kp: T,
+    ki: T,
+    kd: T,
+    b: T,
+    c: T,
+    tf: T,
+    ts: T,
+    output_limit: Option<(T, T)>,
+    anti_windup: AntiWindup<T>,
+    manual: Option<T>,
+    integral: T,
+    derivative: T,
+    reference_z1: T,
+    response_z1: T,
+    initialized: bool,
+}
+
+impl<T: num_traits::Float> PIDController<T> {
+    /* derivative on measurement (c = 0) without filter by default */
+    pub fn new(kp: T, ki: T, kd: T, ts: T) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            b: T::one(),
+            c: T::zero(),
+            tf: T::zero(),
+            ts,
+            output_limit: None,
+            anti_windup: AntiWindup::None,
+            manual: None,
+            integral: T::zero(),
+            derivative: T::zero(),
+            reference_z1: T::zero(),
+            response_z1: T::zero(),
+            initialized: false,
+        }
+    }
+
+    /* first-order filter on the derivative term with the bandwidth g [rad/s] */
+    #[must_use]
+    pub fn set_derivative_filter(mut self, g: T) -> Self {
+        self.tf = T::one() / g;
+        self
+    }
+
+    /* b: proportional, c: derivative */
+    #[must_use]
+    pub fn set_setpoint_weight(mut self, b: T, c: T) -> Self {
+        self.b = b;
+        self.c = c;
+        self
+    }
+
+    #[must_use]
+    pub fn set_output_limit(mut self, min: T, max: T) -> Self {
+        if min > max {
+            panic!("controller setting error: min must not exceed max.")
+        }
+        self.output_limit = Some((min, max));
+        self
+    }
+
+    #[must_use]
+    pub fn set_anti_windup(mut self, anti_windup: AntiWindup<T>) -> Self {
+        if let AntiWindup::BackCalculation(tt) = anti_windup {
+            if tt <= T::zero() || tt.is_nan() {
+                panic!("controller setting error: the tracking time constant must be positive.")
+            }
+        }
+        self.anti_windup = anti_windup;
+        self
+    }
+
+    /* the integral term absorbs the change of the proportional term, so that the output does not kick */
+    pub fn set_gain(&mut self, kp: T, ki: T, kd: T) {
+        let e_p: T = self.b * self.reference_z1 - self.response_z1;
+        self.integral = self.integral + (self.kp - kp) * e_p;
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+    }
+
+    /* the output is held at u within the output limit; the integral term tracks it for bumpless return to auto */
+    pub fn set_manual(&mut self, u: T) {
+        self.manual = Some(u);
+    }
+
+    pub fn set_auto(&mut self) {
+        self.manual = None;
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+
+    pub fn reset(&mut self) {
+        self.integral = T::zero();
+        self.derivative = T::zero();
+        self.initialized = false;
+    }
+
+    fn saturate(&self, u: T) -> T {
+        match self.output_limit {
+            Some((min, max)) => u.max(min).min(max),
+            None => u,
+        }
+    }
+
+    pub fn calc(&mut self, reference: T, response: T) -> T {
+        if !self.initialized {
+            self.reference_z1 = reference;
+            self.response_z1 = response;
+            self.initialized = true;
+        }
+
+        let err: T = reference - response;
+        let p: T = self.kp * (self.b * reference - response);
+
+        //backward difference of c * r - y through the first-order filter
+        let d_err: T = self.c * (reference - self.reference_z1) - (response - self.response_z1);
+        self.derivative = (self.tf * self.derivative + self.kd * d_err) / (self.tf + self.ts);
+
+        self.reference_z1 = reference;
+        self.response_z1 = response;
+
+        if let Some(u_manual) = self.manual {
+            let u: T = self.saturate(u_manual);
+            self.integral = u - p - self.derivative;
+            return u;
+        }
+
+        let v: T = p + self.integral + self.derivative;
+        let u: T = self.saturate(v);
+
+        match self.anti_windup {
+            AntiWindup::None => {
+                self.integral = self.integral + self.ki * err * self.ts;
+            }
+            AntiWindup::Clamping => {
+                let winding_up: bool = (v > u && err > T::zero()) || (v < u && err < T::zero());
+                if !winding_up {
+                    self.integral = self.integral + self.ki * err * self.ts;
+                }
+            }
+            AntiWindup::BackCalculation(tt) => {
+                self.integral = self.integral + (self.ki * err + (u - v) / tt) * self.ts;
+            }
+        }
+
+        u
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::riccati_equation::*;
+use crate::algebra::*;
+use crate::state_space::mimo::{continuous, discrete};
+use num_traits::Float;
+
+/* u = K * (x_ref - x) */
+#[derive(Debug, Copy, Clone)]
+pub struct LQRController<T, const N: usize, const M: usize> {
+    k: Matrix<T, M, N>,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize> LQRController<T, N, M> {
+    /* minimizes the integral of x^T * Q * x + u^T * R * u */
+    pub fn from_continuous_ssr<S, const P: usize>(ssr: S, q: &Matrix<T, N, N>, r: &Matrix<T, M, M>) -> Result<Self, EquationError>
+    where
+        S: Into<continuous::SSR<T, N, M, P>>,
+    {
+        let ssr: continuous::SSR<T, N, M, P> = ssr.into();
+        let x: Matrix<T, N, N> = continuous_algebraic_riccati_equation(&ssr.a, &ssr.b, q, r)?;
+        let r_inv: Matrix<T, M, M> = r.inverse().ok_or(EquationError::SingularWeight)?;
+        Ok(Self { k: r_inv * ssr.b.transpose() * x })
+    }
+
+    /* minimizes the sum of x^T * Q * x + u^T * R * u */
+    pub fn from_discrete_ssr<S, const P: usize>(ssr: S, q: &Matrix<T, N, N>, r: &Matrix<T, M, M>) -> Result<Self, EquationError>
+    where
+        S: Into<discrete::SSR<T, N, M, P>>,
+    {
+        let ssr: discrete::SSR<T, N, M, P> = ssr.into();
+        let x: Matrix<T, N, N> = discrete_algebraic_riccati_equation(&ssr.a, &ssr.b, q, r)?;
+        let btx: Matrix<T, M, N> = ssr.b.transpose() * x;
+        let denom: Matrix<T, M, M> = (*r + btx * ssr.b).inverse().ok_or(EquationError::SingularWeight)?;
+        Ok(Self { k: denom * btx * ssr.a })
+    }
+
+    pub fn gain(&self) -> Matrix<T, M, N> {
+        self.k
+    }
+
+    pub fn calc(&self, reference: &[T; N], state: &[T; N]) -> [T; M] {
+        let err: Vector<T, N> = Vector::from(reference) - Vector::from(state);
+        (self.k * err).data
+    }
+}
+
+/* u = -Kx * x - Ki * z, where z is the integral of the output error r - y */
+#[derive(Debug, Copy, Clone)]
+pub struct LQIController<T, const N: usize, const M: usize, const P: usize> {
+    kx: Matrix<T, M, N>,
+    ki: Matrix<T, M, P>,
+    c: Matrix<T, P, N>,
+    d: Matrix<T, P, M>,
+    z: Vector<T, P>,
+    u: Vector<T, M>,
+    integrator_limit: Option<T>,
+    ts: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    LQIController<T, N, M, P>
+{
+    fn from_augmented_gain(k: Matrix<T, M, { N + P }>, c: Matrix<T, P, N>, d: Matrix<T, P, M>, ts: T) -> Self {
+        let mut kx: Matrix<T, M, N> = Matrix::new();
+        let mut ki: Matrix<T, M, P> = Matrix::new();
+        for i in 0..M {
+            for j in 0..N {
+                kx[i][j] = k[i][j];
+            }
+            for j in 0..P {
+                ki[i][j] = k[i][N + j];
+            }
+        }
+        Self {
+            kx,
+            ki,
+            c,
+            d,
+            z: Vector::new(),
+            u: Vector::new(),
+            integrator_limit: None,
+            ts,
+        }
+    }
+
+    /* Q weights the augmented state [x; z]; the integrator is updated with the sampling time ts */
+    pub fn from_continuous_ssr<S>(ssr: S, q: &Matrix<T, { N + P }, { N + P }>, r: &Matrix<T, M, M>, ts: T) -> Result<Self, EquationError>
+    where
+        S: Into<continuous::SSR<T, N, M, P>>,
+        [(); N + P]:,
+    {
+        let ssr: continuous::SSR<T, N, M, P> = ssr.into();
+
+        //[x; z]' = [[A, 0], [-C, 0]] * [x; z] + [B; -D] * u + [0; I] * r
+        let mut a: Matrix<T, { N + P }, { N + P }> = Matrix::new();
+        let mut b: Matrix<T, { N + P }, M> = Matrix::new();
+        for i in 0..N {
+            for j in 0..N {
+                a[i][j] = ssr.a[i][j];
+            }
+            for j in 0..M {
+                b[i][j] = ssr.b[i][j];
+            }
+        }
+        for i in 0..P {
+            for j in 0..N {
+                a[N + i][j] = -ssr.c[i][j];
+            }
+            for j in 0..M {
+                b[N + i][j] = -ssr.d[i][j];
+            }
+        }
+
+        let x: Matrix<T, { N + P }, { N + P }> = continuous_algebraic_riccati_equation(&a, &b, q, r)?;
+        let r_inv: Matrix<T, M, M> = r.inverse().ok_or(EquationError::SingularWeight)?;
+        let k: Matrix<T, M, { N + P }> = r_inv * b.transpose() * x;
+        Ok(Self::from_augmented_gain(k, ssr.c, ssr.d, ts))
+    }
+
+    /* Q weights the augmented state [x; z] */
+    pub fn from_discrete_ssr<S>(ssr: S, q: &Matrix<T, { N + P }, { N + P }>, r: &Matrix<T, M, M>) -> Result<Self, EquationError>
+    where
+        S: Into<discrete::SSR<T, N, M, P>>,
+        [(); N + P]:,
+    {
+        let ssr: discrete::SSR<T, N, M, P> = ssr.into();
+
+        //[x; z][k + 1] = [[A, 0], [-ts * C, I]] * [x; z][k] + [B; -ts * D] * u[k] + [0; ts * I] * r[k]
+        let mut a: Matrix<T, { N + P }, { N + P }> = Matrix::new();
+        let mut b: Matrix<T, { N + P }, M> = Matrix::new();
+        for i in 0..N {
+            for j in 0..N {
+                a[i][j] = ssr.a[i][j];
+            }
+            for j in 0..M {
+                b[i][j] = ssr.b[i][j];
+            }
+        }
+        for i in 0..P {
+            for j in 0..N {
+                a[N + i][j] = -ssr.c[i][j] * ssr.ts;
+            }
+            a[N + i][N + i] = T::one();
+            for j in 0..M {
+                b[N + i][j] = -ssr.d[i][j] * ssr.ts;
+            }
+        }
+
+        let x: Matrix<T, { N + P }, { N + P }> = discrete_algebraic_riccati_equation(&a, &b, q, r)?;
+        let btx: Matrix<T, M, { N + P }> = b.transpose() * x;
+        let denom: Matrix<T, M, M> = (*r + btx * b).inverse().ok_or(EquationError::SingularWeight)?;
+        let k: Matrix<T, M, { N + P }> = denom * btx * a;
+        Ok(Self::from_augmented_gain(k, ssr.c, ssr.d, ssr.ts))
+    }
+
+    pub fn set_limit(&mut self, limit: T) {
+        self.integrator_limit = Some(limit);
+    }
+
+    pub fn state_gain(&self) -> Matrix<T, M, N> {
+        self.kx
+    }
+
+    pub fn integral_gain(&self) -> Matrix<T, M, P> {
+        self.ki
+    }
+
+    /* the output is estimated as y = C * x + D * u with the previous input */
+    pub fn calc(&mut self, reference: &[T; P], state: &[T; N]) -> [T; M] {
+        let x: Vector<T, N> = Vector::from(state);
+        let y: Vector<T, P> = self.c * x + self.d * self.u;
+
+        self.u = (self.kx * x + self.ki * self.z) * -T::one();
+
+        //the integrator is updated after the input so that z[k + 1] depends on y[k]
+        self.z += (Vector::from(reference) - y) * self.ts;
+        if let Some(limit) = self.integrator_limit {
+            for i in 0..P {
+                self.z[i] = self.z[i].max(-limit).min(limit);
+            }
+        }
+
+        self.u.data
+    }
+}
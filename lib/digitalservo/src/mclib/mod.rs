@@ -1,2 +1,8 @@
+pub mod adrc;
 pub mod controller;
+pub mod lqr;
+pub mod perfect_tracking;
 pub mod trajectory;
+pub mod two_dof;
+pub mod vibration_suppression;
+pub mod zpetc;
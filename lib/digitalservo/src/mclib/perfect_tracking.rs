@@ -0,0 +1,116 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use crate::state_space::blocked::BlockedSSR;
+use crate::state_space::{continuous, discrete};
+use num_traits::Float;
+
+/* multirate perfect tracking control: the input changes N times per frame of N * ts and the state reference */
+/* is reached at every frame by u = B^-1 * (x_ref[i + 1] - A * x_ref[i]) + K * (x_ref[i] - x[i]), */
+/* where A and B are the lifted matrices. The output between frames is not controlled and may ripple */
+/* see H. Fujimoto, Y. Hori and A. Kawamura, "Perfect tracking control based on multirate feedforward control", 2001 */
+#[derive(Debug, Clone, Copy)]
+pub struct PerfectTrackingController<T, const N: usize> {
+    ssr: discrete::SSR<T, N>,
+    lifted: BlockedSSR<T, N>,
+    b_inv: Matrix<T, N, N>,
+    k: Matrix<T, N, N>,
+    reference: Vector<T, N>,
+    feedforward: [T; N],
+    feedback: [T; N],
+    index: usize,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> PerfectTrackingController<T, N> {
+    /* zero-order hold with the input period ts; returns None if the lifted input matrix is singular */
+    pub fn new(ssr: &continuous::SSR<T, N>, ts: T) -> Option<Self> {
+        Self::from_discrete_ssr(&discrete::SSR::from_continuous_ssr(ssr, ts))
+    }
+
+    pub fn from_discrete_ssr(ssr: &discrete::SSR<T, N>) -> Option<Self> {
+        let lifted: BlockedSSR<T, N> = BlockedSSR::from_discrete_ssr(ssr);
+        Some(Self {
+            ssr: *ssr,
+            lifted,
+            b_inv: lifted.b.inverse()?,
+            k: Matrix::new(),
+            reference: Vector::new(),
+            feedforward: [T::zero(); N],
+            feedback: [T::zero(); N],
+            index: 0,
+        })
+    }
+
+    /* gain on the frame-rate state error x_ref[i] - x[i], zero by default */
+    #[must_use]
+    pub fn set_state_feedback(mut self, k: &Matrix<T, N, N>) -> Self {
+        self.k = *k;
+        self
+    }
+
+    /* K = B^-1 * A, which places all the lifted poles at the origin */
+    #[must_use]
+    pub fn set_deadbeat_feedback(mut self) -> Self {
+        self.k = self.b_inv * self.lifted.a;
+        self
+    }
+
+    /* state reference at the current frame */
+    #[must_use]
+    pub fn set_initial_reference(mut self, reference: &[T; N]) -> Self {
+        self.reference = Vector::from(reference);
+        self
+    }
+
+    /* frame period N * ts */
+    pub fn frame_period(&self) -> T {
+        self.lifted.ts * T::from(N).unwrap()
+    }
+
+    pub fn feedforward(&self) -> [T; N] {
+        self.feedforward
+    }
+
+    pub fn feedback(&self) -> [T; N] {
+        self.feedback
+    }
+
+    /* called once per frame with the state reference of the next frame; returns the input block */
+    pub fn calc(&mut self, reference_next: &[T; N], state: &[T; N]) -> [T; N] {
+        let reference_next: Vector<T, N> = Vector::from(reference_next);
+        let error: Vector<T, N> = self.reference - Vector::from(state);
+        self.feedforward = (self.b_inv * (reference_next - self.lifted.a * self.reference)).data;
+        self.feedback = (self.k * error).data;
+        self.reference = reference_next;
+        self.index = 0;
+
+        std::array::from_fn(|i| self.feedforward[i] + self.feedback[i])
+    }
+
+    /* called every ts after calc(); the last input of the block is held */
+    pub fn hold(&mut self) -> T {
+        let u: T = self.feedforward[self.index] + self.feedback[self.index];
+        if self.index < N - 1 {
+            self.index += 1;
+        }
+        u
+    }
+
+    /* outputs at t = 0, ts, ..., (N - 1) * ts from the frame start driven by the input block, for checking the */
+    /* intersample ripple; y[i] = C * x[i] + D * u[i] as discrete::Plant::update() gives */
+    pub fn intersample_output(&self, state: &[T; N], u: &[T; N]) -> [T; N] {
+        let mut x: Vector<T, N> = Vector::from(state);
+        let mut y: [T; N] = [T::zero(); N];
+        for i in 0..N {
+            y[i] = self.ssr.c.dot(x) + self.ssr.d * u[i];
+            x = self.ssr.a * x + self.ssr.b * u[i];
+        }
+        y
+    }
+
+    /* maximum deviation of intersample_output() from the output reference, reference[i] given at t = i * ts */
+    pub fn intersample_error(&self, state: &[T; N], u: &[T; N], reference: &[T; N]) -> T {
+        let y: [T; N] = self.intersample_output(state, u);
+        (0..N).fold(T::zero(), |acc, i| acc.max((y[i] - reference[i]).abs()))
+    }
+}
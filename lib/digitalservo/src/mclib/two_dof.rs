@@ -0,0 +1,167 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use crate::combinatorics;
+use crate::state_space::continuous;
+use crate::transfer_function::{CanonicalForm, TransferFunction};
+use num_traits::Float;
+
+/* contributions of a single control step, kept separately for logging */
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TwoDOFOutput<T> {
+    /* nominal output y_n = P_n(s) u_ff, which the feedback compares with the response */
+    pub nominal: T,
+    pub feedforward: T,
+    pub feedback: T,
+    pub input: T,
+}
+
+/* w^K / (s + w)^K in the controllable canonical form, a unity-gain Q-filter for the inverse nominal model */
+pub fn q_filter<T: Float + Default, const K: usize>(bandwidth: T) -> continuous::SSR<T, K> {
+    if K == 0 {
+        panic!("filter setting error: K must be positive.")
+    }
+
+    let mut a: [[T; K]; K] = [[T::zero(); K]; K];
+    let mut b: [T; K] = [T::zero(); K];
+    let mut c: [T; K] = [T::zero(); K];
+    for i in 0..K - 1 {
+        a[i][i + 1] = T::one();
+    }
+    a[K - 1] = std::array::from_fn(|i| {
+        -T::from(combinatorics::combination(K, i)).unwrap() * bandwidth.powi((K - i) as i32)
+    });
+    b[K - 1] = T::one();
+    c[0] = bandwidth.powi(K as i32);
+    continuous::SSR::new(&a, &b, &c)
+}
+
+/* relative degree r and the rows c * A^r, c * A^(r-1) * b (d for r = 0); None if the output never depends on the input */
+fn output_derivative<T, const N: usize>(ssr: &continuous::SSR<T, N>) -> Option<(usize, Vector<T, N>, T)>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    if ssr.d != T::zero() {
+        return Some((0, ssr.c, ssr.d));
+    }
+    let at: Matrix<T, N, N> = ssr.a.transpose();
+    let mut row: Vector<T, N> = ssr.c;
+    for r in 1..N + 1 {
+        let gain: T = row.dot(ssr.b);
+        row = at * row;
+        if gain != T::zero() {
+            return Some((r, row, gain));
+        }
+    }
+    None
+}
+
+/* u = u_ff + C(y_n - y): the nominal model is driven by the feedforward u_ff so that its output y_n follows */
+/* the reference model M(s) exactly, i.e. u_ff = P_n(s)^-1 M(s) r, and the feedback C only acts on the deviation */
+/* from the nominal response. The nominal model must be minimum phase and M(s) must have at least its relative degree */
+pub struct TwoDOFController<T, F, const N: usize, const K: usize> {
+    ts: T,
+    plant: continuous::SSR<T, N>,
+    model: continuous::SSR<T, K>,
+    plant_row: Vector<T, N>,
+    plant_gain: T,
+    model_row: Vector<T, K>,
+    model_gain: T,
+    xn: Vector<T, N>,
+    xm: Vector<T, K>,
+    feedback: F,
+    output: TwoDOFOutput<T>,
+}
+
+impl<T, F, const N: usize, const K: usize> TwoDOFController<T, F, N, K>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    F: FnMut(T, T) -> T,
+{
+    /* feedback(reference, response) is typically a closure over one of the controllers in this module; */
+    /* derivative on measurement would counteract the feedforward, so use PIDController with set_setpoint_weight(1, 1) */
+    pub fn new(plant: &continuous::SSR<T, N>, model: &continuous::SSR<T, K>, feedback: F, ts: T) -> Option<Self> {
+        let (r, plant_row, plant_gain) = output_derivative(plant)?;
+
+        /* the r-th derivative of the model output must not depend on derivatives of the reference */
+        let (rm, model_row, model_gain) = match output_derivative(model) {
+            Some(x) => x,
+            None => (usize::MAX, Vector::new(), T::zero()),
+        };
+        if rm < r {
+            return None;
+        }
+        let model_gain: T = if rm == r { model_gain } else { T::zero() };
+        let mut model_row: Vector<T, K> = if rm == r { model_row } else { model.c };
+        if rm != r {
+            for _ in 0..r {
+                model_row = model.a.transpose() * model_row;
+            }
+        }
+
+        Some(Self {
+            ts,
+            plant: *plant,
+            model: *model,
+            plant_row,
+            plant_gain,
+            model_row,
+            model_gain,
+            xn: Vector::new(),
+            xm: Vector::new(),
+            feedback,
+            output: TwoDOFOutput::default(),
+        })
+    }
+
+    /* inverse nominal model filtered by q_filter() of order K */
+    pub fn with_q_filter(plant: &continuous::SSR<T, N>, bandwidth: T, feedback: F, ts: T) -> Option<Self> {
+        Self::new(plant, &q_filter(bandwidth), feedback, ts)
+    }
+
+    pub fn output(&self) -> TwoDOFOutput<T> {
+        self.output
+    }
+
+    pub fn reset(&mut self) {
+        self.xn = Vector::new();
+        self.xm = Vector::new();
+        self.output = TwoDOFOutput::default();
+    }
+
+    /* returns the plant input u_ff + u_fb */
+    pub fn calc(&mut self, reference: T, response: T) -> T {
+        let model_derivative: T = self.model_row.dot(self.xm) + self.model_gain * reference;
+        let feedforward: T = (model_derivative - self.plant_row.dot(self.xn)) / self.plant_gain;
+        let nominal: T = self.plant.c.dot(self.xn) + self.plant.d * feedforward;
+        let feedback: T = (self.feedback)(nominal, response);
+
+        let dxm: Vector<T, K> = self.model.a * self.xm + self.model.b * reference;
+        let dxn: Vector<T, N> = self.plant.a * self.xn + self.plant.b * feedforward;
+        self.xm += dxm * self.ts;
+        self.xn += dxn * self.ts;
+
+        self.output = TwoDOFOutput {
+            nominal,
+            feedforward,
+            feedback,
+            input: feedforward + feedback,
+        };
+        self.output.input
+    }
+}
+
+/* the nominal model given as a continuous transfer function of order P - 1 */
+pub fn from_transfer_function<T, F, const Z: usize, const P: usize, const K: usize>(
+    tf: &TransferFunction<T, Z, P>,
+    model: &continuous::SSR<T, K>,
+    feedback: F,
+    ts: T,
+) -> Option<TwoDOFController<T, F, { P - 1 }, K>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    F: FnMut(T, T) -> T,
+{
+    let plant: continuous::SSR<T, { P - 1 }> = tf.to_continuous_ssr(CanonicalForm::Controllable)?;
+    TwoDOFController::new(&plant, model, feedback, ts)
+}
@@ -0,0 +1,177 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use super::controller::PIController;
+use crate::observer::load_side_observer::LoadSideObserver;
+use crate::plant::two_inertia::TwoInertiaParameter;
+use crate::signal::notchfilter::NotchFilter;
+use num_traits::Float;
+
+/* speed PI control of the two-inertia system whose motor behaves as the virtual inertia jv */
+#[derive(Debug, Copy, Clone)]
+pub struct SpeedLoopDesign<T> {
+    pub jv: T,
+    pub kp: T,
+    pub ki: T,
+}
+
+/* jl / jm = (wr / wa)^2 - 1 */
+pub fn inertia_ratio<T: Float>(wr: T, wa: T) -> T {
+    (wr / wa).powi(2) - T::one()
+}
+
+/* virtual motor inertia which realizes the resonance ratio h = sqrt(1 + jl / jv) */
+pub fn virtual_inertia<T: Float>(jm: T, wr: T, wa: T, h: T) -> T {
+    jm * inertia_ratio(wr, wa) / (h * h - T::one())
+}
+
+/* all the four closed-loop poles at -wa, which requires the resonance ratio sqrt(5) */
+/* see Y. Hori, "Vibration suppression and disturbance rejection control on torsional systems", 1994 */
+pub fn design_speed_loop<T: Float>(jm: T, wr: T, wa: T) -> SpeedLoopDesign<T> {
+    let jv: T = virtual_inertia(jm, wr, wa, T::from(5.0).unwrap().sqrt());
+    SpeedLoopDesign {
+        jv,
+        kp: T::from(4.0).unwrap() * wa * jv,
+        ki: wa * wa * jv,
+    }
+}
+
+/* tau_m = K * tau_ref + (1 - K) * torsion, so that the motor behaves as jm / K */
+#[derive(Debug, Copy, Clone)]
+pub struct ResonanceRatioController<T> {
+    ts: T,
+    kt: T,
+    jm: T,
+    wr: T,
+    wa: T,
+    k: T,
+    pi: PIController<T>,
+    observer: LoadSideObserver<T>,
+    i: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign> ResonanceRatioController<T> {
+    /* gains from design_speed_loop(); the torsion is estimated with the load-side observer */
+    pub fn new(ts: T, kt: T, param: &TwoInertiaParameter<T>, observer_bandwidth: T) -> Option<Self> {
+        let wr: T = param.resonance_frequency();
+        let wa: T = param.antiresonance_frequency();
+        let design: SpeedLoopDesign<T> = design_speed_loop(param.jm, wr, wa);
+        Some(Self {
+            ts,
+            kt,
+            jm: param.jm,
+            wr,
+            wa,
+            k: param.jm / design.jv,
+            pi: PIController::new(design.kp, design.ki, ts),
+            observer: LoadSideObserver::new(ts, kt, param, observer_bandwidth)?,
+            i: T::zero(),
+        })
+    }
+
+    /* torsion feedback gain K for the resonance ratio h */
+    #[must_use]
+    pub fn set_resonance_ratio(mut self, h: T) -> Self {
+        self.k = self.jm / virtual_inertia(self.jm, self.wr, self.wa, h);
+        self
+    }
+
+    /* PI gains in torque per velocity error */
+    #[must_use]
+    pub fn set_speed_gain(mut self, kp: T, ki: T) -> Self {
+        self.pi = PIController::new(kp, ki, self.ts);
+        self
+    }
+
+    pub fn gain(&self) -> T {
+        self.k
+    }
+
+    /* returns the current reference */
+    pub fn calc(&mut self, reference: T, wm: T) -> T {
+        let torsion: T = self.observer.update(self.i, wm).torsion;
+        let tau_ref: T = self.pi.calc(reference, wm);
+        self.i = (self.k * tau_ref + (T::one() - self.k) * torsion) / self.kt;
+        self.i
+    }
+}
+
+/* speed PI control followed by a notch filter at the resonance frequency */
+#[derive(Debug, Copy, Clone)]
+pub struct NotchController<T> {
+    pi: PIController<T>,
+    notch: NotchFilter<T>,
+}
+
+impl<T: Float> NotchController<T> {
+    pub fn new(kp: T, ki: T, ts: T, wn: T, zeta_n: T, zeta_d: T) -> Self {
+        Self {
+            pi: PIController::new(kp, ki, ts),
+            notch: NotchFilter::new(ts, wn, zeta_n, zeta_d),
+        }
+    }
+
+    /* full notch at wr whose width reaches wa; rigid-body PI gains with the bandwidth wa / 2 on jm + jl */
+    pub fn from_resonance(ts: T, jm: T, wr: T, wa: T) -> Self {
+        let t2: T = T::from(2.0).unwrap();
+        let zeta_d: T = (wr - wa) / wr;
+        let wc: T = wa / t2;
+        let kp: T = jm * (T::one() + inertia_ratio(wr, wa)) * wc;
+        let ki: T = kp * wc / T::from(4.0).unwrap();
+        Self::new(kp, ki, ts, wr, T::zero(), zeta_d)
+    }
+
+    /* returns the torque reference */
+    pub fn calc(&mut self, reference: T, response: T) -> T {
+        let tau_ref: T = self.pi.calc(reference, response);
+        self.notch.update(tau_ref)
+    }
+}
+
+/* tau_m = tau_ref - ka * jm * am, so that the motor behaves as (1 + ka) * jm */
+#[derive(Debug, Copy, Clone)]
+pub struct AccelerationFeedbackController<T> {
+    ts: T,
+    jm: T,
+    wr: T,
+    wa: T,
+    ka: T,
+    pi: PIController<T>,
+}
+
+impl<T: Float> AccelerationFeedbackController<T> {
+    /* gains from design_speed_loop(); ka < 0 if jl < 4 * jm */
+    pub fn new(ts: T, jm: T, wr: T, wa: T) -> Self {
+        let design: SpeedLoopDesign<T> = design_speed_loop(jm, wr, wa);
+        Self {
+            ts,
+            jm,
+            wr,
+            wa,
+            ka: design.jv / jm - T::one(),
+            pi: PIController::new(design.kp, design.ki, ts),
+        }
+    }
+
+    /* acceleration feedback gain ka for the resonance ratio h */
+    #[must_use]
+    pub fn set_resonance_ratio(mut self, h: T) -> Self {
+        self.ka = virtual_inertia(self.jm, self.wr, self.wa, h) / self.jm - T::one();
+        self
+    }
+
+    /* PI gains in torque per velocity error */
+    #[must_use]
+    pub fn set_speed_gain(mut self, kp: T, ki: T) -> Self {
+        self.pi = PIController::new(kp, ki, self.ts);
+        self
+    }
+
+    pub fn gain(&self) -> T {
+        self.ka
+    }
+
+    /* returns the torque reference from the motor velocity and acceleration */
+    pub fn calc(&mut self, reference: T, wm: T, am: T) -> T {
+        self.pi.calc(reference, wm) - self.ka * self.jm * am
+    }
+}
@@ -0,0 +1,189 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::transfer_function::TransferFunction;
+use num_complex::Complex;
+use num_traits::Float;
+
+/* feedforward for a discrete plant G(z^-1) = z^-d * Ba(z^-1) * Bu(z^-1) / A(z^-1), where Ba holds the zeros inside */
+/* the given radius and Bu the unacceptable ones (outside the unit circle, or lightly damped if radius < 1). */
+/* ZPETC: u(k) = A(z^-1) * Bu(z) / (Ba(z^-1) * Bu(1)^2) r(k + d), which has no phase error */
+/* ZMETC: u(k) = A(z^-1) / (Ba(z^-1) * z^-s * Bu(z)) r(k + d), which has no gain error */
+/* see M. Tomizuka, "Zero phase error tracking algorithm for digital control", 1987 */
+#[derive(Debug, Clone, Copy)]
+pub struct TrackingFeedforward<T, const Z: usize, const P: usize>
+where
+    [(); P + Z]:,
+{
+    numer: [T; P + Z],
+    denom: [T; Z],
+    preview: usize,
+    r: [T; P + Z],
+    u: [T; Z],
+}
+
+impl<T, const Z: usize, const P: usize> TrackingFeedforward<T, Z, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); P + Z]:,
+    [(); Z - 1]:,
+{
+    /* returns None if the plant is continuous or improper, or its zeros are not found */
+    pub fn zpetc(plant: &TransferFunction<T, Z, P>, radius: T) -> Option<Self> {
+        let split: ZeroSplit<T, Z, P> = ZeroSplit::new(plant, radius)?;
+
+        /* A(z^-1) * z^-s * Bu(z) / (b0 * Bu(1)^2), the reversed Bu looks s samples further ahead */
+        let bu_1: T = split.unacceptable.iter().fold(T::zero(), |acc, &x| acc + x);
+        let mut bu_rev: [T; Z] = [T::zero(); Z];
+        bu_rev[..split.s + 1].copy_from_slice(&split.unacceptable[..split.s + 1]);
+        bu_rev[..split.s + 1].reverse();
+        let mut numer: [T; P + Z] = [T::zero(); P + Z];
+        for i in 0..P {
+            for j in 0..split.s + 1 {
+                numer[i + j] += split.a[i] * bu_rev[j] / (split.b0 * bu_1 * bu_1);
+            }
+        }
+
+        Some(Self::from_coefficients(numer, split.acceptable, split.d + split.s))
+    }
+
+    /* returns None as well if an unacceptable zero lies inside or on the unit circle, where its reflection is unstable */
+    pub fn zmetc(plant: &TransferFunction<T, Z, P>, radius: T) -> Option<Self> {
+        let split: ZeroSplit<T, Z, P> = ZeroSplit::new(plant, radius)?;
+        if !split.is_reflectable {
+            return None;
+        }
+
+        /* Ba(z^-1) * z^-s * Bu(z), normalized to a monic denominator */
+        let lead: T = split.unacceptable[split.s];
+        let mut denom: [T; Z] = [T::zero(); Z];
+        for i in 0..Z {
+            for j in 0..split.s + 1 {
+                if i + j < Z {
+                    denom[i + j] += split.acceptable[i] * split.unacceptable[split.s - j] / lead;
+                }
+            }
+        }
+        let mut numer: [T; P + Z] = [T::zero(); P + Z];
+        for (x, a) in numer.iter_mut().zip(split.a.iter()) {
+            *x = *a / (split.b0 * lead);
+        }
+
+        Some(Self::from_coefficients(numer, denom, split.d))
+    }
+
+    fn from_coefficients(numer: [T; P + Z], denom: [T; Z], preview: usize) -> Self {
+        Self {
+            numer,
+            denom,
+            preview,
+            r: [T::zero(); P + Z],
+            u: [T::zero(); Z],
+        }
+    }
+
+    /* the reference has to be given this many samples ahead */
+    pub fn preview(&self) -> usize {
+        self.preview
+    }
+
+    /* coefficients in ascending powers of z^-1; the denominator is monic */
+    pub fn numerator(&self) -> [T; P + Z] {
+        self.numer
+    }
+
+    pub fn denominator(&self) -> [T; Z] {
+        self.denom
+    }
+
+    pub fn reset(&mut self) {
+        self.r = [T::zero(); P + Z];
+        self.u = [T::zero(); Z];
+    }
+
+    /* returns u(k) from r(k + preview) */
+    pub fn update(&mut self, reference: T) -> T {
+        self.r.copy_within(0..P + Z - 1, 1);
+        self.r[0] = reference;
+
+        let mut u: T = T::zero();
+        for i in 0..P + Z {
+            u += self.numer[i] * self.r[i];
+        }
+        for i in 1..Z {
+            u -= self.denom[i] * self.u[i - 1];
+        }
+
+        if Z > 1 {
+            self.u.copy_within(0..Z - 1, 1);
+        }
+        self.u[0] = u;
+        u
+    }
+}
+
+/* plant coefficients in ascending powers of z^-1 with the numerator zeros split at the radius */
+struct ZeroSplit<T, const Z: usize, const P: usize> {
+    a: [T; P],
+    b0: T,
+    acceptable: [T; Z],
+    unacceptable: [T; Z],
+    d: usize,
+    s: usize,
+    is_reflectable: bool,
+}
+
+impl<T, const Z: usize, const P: usize> ZeroSplit<T, Z, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); Z - 1]:,
+{
+    fn new(plant: &TransferFunction<T, Z, P>, radius: T) -> Option<Self> {
+        if !plant.is_discrete() {
+            return None;
+        }
+        let n_lead: usize = plant.denom.iter().position(|&x| x != T::zero())?;
+        let m_lead: usize = plant.numer.iter().position(|&x| x != T::zero())?;
+        if Z - m_lead > P - n_lead {
+            return None;
+        }
+
+        let a0: T = plant.denom[n_lead];
+        let mut a: [T; P] = [T::zero(); P];
+        a[..P - n_lead].copy_from_slice(&plant.denom[n_lead..]);
+
+        //degenerate leading coefficients give zeros at infinity, which are part of the delay d
+        let zeros: [Complex<T>; Z - 1] = plant.zeros()?;
+        let mut acceptable: [Complex<T>; Z] = [Complex::new(T::zero(), T::zero()); Z];
+        let mut unacceptable: [Complex<T>; Z] = [Complex::new(T::zero(), T::zero()); Z];
+        acceptable[0] = Complex::new(T::one(), T::zero());
+        unacceptable[0] = Complex::new(T::one(), T::zero());
+        let mut s: usize = 0;
+        let mut is_reflectable: bool = true;
+        for z in zeros.iter().filter(|z| z.re.is_finite() && z.im.is_finite()) {
+            if z.norm() < radius {
+                multiply_root(&mut acceptable, *z);
+            } else {
+                multiply_root(&mut unacceptable, *z);
+                is_reflectable &= z.norm() > T::one();
+                s += 1;
+            }
+        }
+
+        Some(Self {
+            a: a.map(|x| x / a0),
+            b0: plant.numer[m_lead] / a0,
+            acceptable: acceptable.map(|x| x.re),
+            unacceptable: unacceptable.map(|x| x.re),
+            d: (P - n_lead) - (Z - m_lead),
+            s,
+            is_reflectable,
+        })
+    }
+}
+
+/* p(z^-1) * (1 - root * z^-1) */
+fn multiply_root<T: Float, const N: usize>(p: &mut [Complex<T>; N], root: Complex<T>) {
+    for i in (1..N).rev() {
+        p[i] = p[i] - root * p[i - 1];
+    }
+}
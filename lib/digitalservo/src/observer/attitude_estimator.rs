@@ -0,0 +1,362 @@
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+use super::quaternion_observer::{DirectionCosineMatrix, ObserverOutput, GEOMAG_R, GRAVITY_R, JOINTSPACE_DIM};
+use crate::algebra::*;
+use num_traits::Float;
+
+/* multiplicative EKF: q = q_hat * dq(dtheta), error state [dtheta; dbias] */
+/* omega = omega_sense + omega_bias_hat, acc_b = R^T * g + force / mb, geomag_b = R^T * m */
+pub struct MultiplicativeEKF<T> {
+    pub mb: Vector<T, JOINTSPACE_DIM>,
+    pub q_hat: Vector<T, 4>,
+    pub dq_hat: Vector<T, 4>,
+    pub omega_hat: Vector<T, 3>,
+    pub omega_bias_hat: Vector<T, JOINTSPACE_DIM>,
+    covariance: Matrix<T, 6, 6>,
+    gyro_noise: T,
+    bias_noise: T,
+    acc_noise: T,
+    geomag_noise: T,
+    pub ts: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign + DivAssign> MultiplicativeEKF<T> {
+    pub fn new(ts: T) -> Self {
+        Self {
+            mb: Vector::new(),
+            q_hat: Vector::from([T::one(), T::zero(), T::zero(), T::zero()]),
+            dq_hat: Vector::new(),
+            omega_hat: Vector::new(),
+            omega_bias_hat: Vector::new(),
+            covariance: Matrix::diag(T::one()),
+            gyro_noise: T::one(),
+            bias_noise: T::one(),
+            acc_noise: T::one(),
+            geomag_noise: T::one(),
+            ts,
+        }
+    }
+
+    #[must_use]
+    pub fn set_mb(mut self, param: &[T; 3]) -> Self {
+        self.mb = Vector::from(param);
+        self
+    }
+
+    #[must_use]
+    pub fn set_init_quartenion(mut self, param: &[T; 4]) -> Self {
+        self.q_hat = Vector::from(param);
+        self
+    }
+
+    /* standard deviations: gyro [rad/s/sqrt(Hz)], bias random walk [rad/s^2/sqrt(Hz)], accelerometer [m/s^2], magnetometer */
+    #[must_use]
+    pub fn set_noise(mut self, gyro: T, bias: T, acc: T, geomag: T) -> Self {
+        self.gyro_noise = gyro;
+        self.bias_noise = bias;
+        self.acc_noise = acc;
+        self.geomag_noise = geomag;
+        self
+    }
+
+    /* initial standard deviations of the attitude [rad] and of the gyro bias [rad/s] */
+    #[must_use]
+    pub fn set_init_covariance(mut self, attitude: T, bias: T) -> Self {
+        self.covariance = Matrix::new();
+        for i in 0..3 {
+            self.covariance[i][i] = attitude * attitude;
+            self.covariance[3 + i][3 + i] = bias * bias;
+        }
+        self
+    }
+
+    pub fn covariance(&self) -> Matrix<T, 6, 6> {
+        self.covariance
+    }
+
+    pub fn estimate(
+        &mut self,
+        omega_sense: &[T; 3],
+        force: &[T; 3],
+        acc_sense: &[T; 3],
+        geomag_sense: &[T; 3],
+    ) -> ObserverOutput<T> {
+        let omega_sense: Vector<T, 3> = Vector::from(omega_sense);
+        let dyn_acc_b_predict: Vector<T, 3> = dynamic_acceleration(force, &self.mb);
+
+        //Prediction (quaternion)
+        self.omega_hat = omega_sense + self.omega_bias_hat;
+        self.dq_hat = quaternion_rate(&self.q_hat, &self.omega_hat);
+        self.q_hat = (self.q_hat + self.dq_hat * self.ts).normalize();
+
+        //Prediction (covariance): dtheta' = -omega x dtheta + dbias
+        let mut f: Matrix<T, 6, 6> = Matrix::diag(T::one());
+        let omega_skew: Matrix<T, 3, 3> = skew(&self.omega_hat);
+        let mut q: Matrix<T, 6, 6> = Matrix::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                f[i][j] -= omega_skew[i][j] * self.ts;
+            }
+            f[i][3 + i] = self.ts;
+            q[i][i] = self.gyro_noise * self.gyro_noise * self.ts;
+            q[3 + i][3 + i] = self.bias_noise * self.bias_noise * self.ts;
+        }
+        self.covariance = (f * self.covariance * f.transpose() + q).symmetrize();
+
+        //Observation: R^T * v is perturbed by (R^T * v) x dtheta
+        let (gravity_b, geomag_b) = body_references(&self.q_hat);
+        let acc_b_est: Vector<T, 3> = gravity_b + dyn_acc_b_predict;
+        let gravity_skew: Matrix<T, 3, 3> = skew(&gravity_b);
+        let geomag_skew: Matrix<T, 3, 3> = skew(&geomag_b);
+        let mut h: Matrix<T, 6, 6> = Matrix::new();
+        let mut r: Matrix<T, 6, 6> = Matrix::new();
+        let mut y_err: Vector<T, 6> = Vector::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                h[i][j] = gravity_skew[i][j];
+                h[3 + i][j] = geomag_skew[i][j];
+            }
+            r[i][i] = self.acc_noise * self.acc_noise;
+            r[3 + i][3 + i] = self.geomag_noise * self.geomag_noise;
+            y_err[i] = acc_sense[i] - acc_b_est[i];
+            y_err[3 + i] = geomag_sense[i] - geomag_b[i];
+        }
+
+        //Feedback
+        let h_t: Matrix<T, 6, 6> = h.transpose();
+        if let Some(innovation_inv) = (h * self.covariance * h_t + r).inverse() {
+            let k: Matrix<T, 6, 6> = self.covariance * h_t * innovation_inv;
+            let dx: Vector<T, 6> = k * y_err;
+
+            let half: T = T::from(0.5).unwrap();
+            let dq: Vector<T, 4> = Vector::from([T::one(), dx[0] * half, dx[1] * half, dx[2] * half]);
+            self.q_hat = quaternion_product(&self.q_hat, &dq).normalize();
+            for i in 0..3 {
+                self.omega_bias_hat[i] += dx[3 + i];
+            }
+
+            let identity: Matrix<T, 6, 6> = Matrix::diag(T::one());
+            let ikh: Matrix<T, 6, 6> = identity - k * h;
+            self.covariance = (ikh * self.covariance * ikh.transpose() + k * r * k.transpose()).symmetrize();
+        }
+
+        ObserverOutput {
+            q: self.q_hat.data,
+            dq: self.dq_hat.data,
+            omega: self.omega_hat.data,
+        }
+    }
+}
+
+/* gradient descent on |R^T * g - acc|^2 + |R^T * m - geomag|^2 with normalized directions */
+/* see S. O. H. Madgwick et al., "Estimation of IMU and MARG orientation using a gradient descent algorithm", 2011 */
+pub struct MadgwickFilter<T> {
+    pub mb: Vector<T, JOINTSPACE_DIM>,
+    pub beta: T,
+    pub zeta: T,
+    pub q_hat: Vector<T, 4>,
+    pub dq_hat: Vector<T, 4>,
+    pub omega_hat: Vector<T, 3>,
+    pub omega_bias_hat: Vector<T, JOINTSPACE_DIM>,
+    pub ts: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign + DivAssign> MadgwickFilter<T> {
+    pub fn new(ts: T) -> Self {
+        Self {
+            mb: Vector::new(),
+            beta: T::zero(),
+            zeta: T::zero(),
+            q_hat: Vector::from([T::one(), T::zero(), T::zero(), T::zero()]),
+            dq_hat: Vector::new(),
+            omega_hat: Vector::new(),
+            omega_bias_hat: Vector::new(),
+            ts,
+        }
+    }
+
+    #[must_use]
+    pub fn set_mb(mut self, param: &[T; 3]) -> Self {
+        self.mb = Vector::from(param);
+        self
+    }
+
+    /* beta: convergence rate of the quaternion [1/s], zeta: convergence rate of the gyro bias [rad/s^2] */
+    #[must_use]
+    pub fn set_gain(mut self, beta: T, zeta: T) -> Self {
+        self.beta = beta;
+        self.zeta = zeta;
+        self
+    }
+
+    #[must_use]
+    pub fn set_init_quartenion(mut self, param: &[T; 4]) -> Self {
+        self.q_hat = Vector::from(param);
+        self
+    }
+
+    pub fn estimate(
+        &mut self,
+        omega_sense: &[T; 3],
+        force: &[T; 3],
+        acc_sense: &[T; 3],
+        geomag_sense: &[T; 3],
+    ) -> ObserverOutput<T> {
+        let omega_sense: Vector<T, 3> = Vector::from(omega_sense);
+        let dyn_acc_b_predict: Vector<T, 3> = dynamic_acceleration(force, &self.mb);
+        let acc_sense: Vector<T, 3> = Vector::from(acc_sense) - dyn_acc_b_predict;
+        let geomag_sense: Vector<T, 3> = Vector::from(geomag_sense);
+
+        //the normalized gradient is q * [0, -e / |e|]
+        let e: Vector<T, 3> = direction_error(&self.q_hat, &acc_sense, &geomag_sense).normalize();
+
+        self.omega_bias_hat += e * (self.zeta * self.ts);
+        self.omega_hat = omega_sense + self.omega_bias_hat;
+        self.dq_hat = quaternion_rate(&self.q_hat, &self.omega_hat);
+
+        let omega_corrected: Vector<T, 3> = self.omega_hat + e * (self.beta + self.beta);
+        let dq: Vector<T, 4> = quaternion_rate(&self.q_hat, &omega_corrected);
+        self.q_hat = (self.q_hat + dq * self.ts).normalize();
+
+        ObserverOutput {
+            q: self.q_hat.data,
+            dq: self.dq_hat.data,
+            omega: self.omega_hat.data,
+        }
+    }
+}
+
+/* explicit complementary filter: omega + kp * e + ki * integral of e */
+/* see R. Mahony et al., "Nonlinear complementary filters on the special orthogonal group", 2008 */
+pub struct MahonyFilter<T> {
+    pub mb: Vector<T, JOINTSPACE_DIM>,
+    pub kp: T,
+    pub ki: T,
+    pub q_hat: Vector<T, 4>,
+    pub dq_hat: Vector<T, 4>,
+    pub omega_hat: Vector<T, 3>,
+    pub omega_bias_hat: Vector<T, JOINTSPACE_DIM>,
+    pub ts: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign + DivAssign> MahonyFilter<T> {
+    pub fn new(ts: T) -> Self {
+        Self {
+            mb: Vector::new(),
+            kp: T::zero(),
+            ki: T::zero(),
+            q_hat: Vector::from([T::one(), T::zero(), T::zero(), T::zero()]),
+            dq_hat: Vector::new(),
+            omega_hat: Vector::new(),
+            omega_bias_hat: Vector::new(),
+            ts,
+        }
+    }
+
+    #[must_use]
+    pub fn set_mb(mut self, param: &[T; 3]) -> Self {
+        self.mb = Vector::from(param);
+        self
+    }
+
+    #[must_use]
+    pub fn set_gain(mut self, kp: T, ki: T) -> Self {
+        self.kp = kp;
+        self.ki = ki;
+        self
+    }
+
+    #[must_use]
+    pub fn set_init_quartenion(mut self, param: &[T; 4]) -> Self {
+        self.q_hat = Vector::from(param);
+        self
+    }
+
+    pub fn estimate(
+        &mut self,
+        omega_sense: &[T; 3],
+        force: &[T; 3],
+        acc_sense: &[T; 3],
+        geomag_sense: &[T; 3],
+    ) -> ObserverOutput<T> {
+        let omega_sense: Vector<T, 3> = Vector::from(omega_sense);
+        let dyn_acc_b_predict: Vector<T, 3> = dynamic_acceleration(force, &self.mb);
+        let acc_sense: Vector<T, 3> = Vector::from(acc_sense) - dyn_acc_b_predict;
+        let geomag_sense: Vector<T, 3> = Vector::from(geomag_sense);
+
+        let e: Vector<T, 3> = direction_error(&self.q_hat, &acc_sense, &geomag_sense);
+
+        self.omega_bias_hat += e * (self.ki * self.ts);
+        self.omega_hat = omega_sense + self.omega_bias_hat;
+        self.dq_hat = quaternion_rate(&self.q_hat, &self.omega_hat);
+
+        let omega_corrected: Vector<T, 3> = self.omega_hat + e * self.kp;
+        let dq: Vector<T, 4> = quaternion_rate(&self.q_hat, &omega_corrected);
+        self.q_hat = (self.q_hat + dq * self.ts).normalize();
+
+        ObserverOutput {
+            q: self.q_hat.data,
+            dq: self.dq_hat.data,
+            omega: self.omega_hat.data,
+        }
+    }
+}
+
+fn dynamic_acceleration<T: Float + Default>(force: &[T; 3], mb: &Vector<T, 3>) -> Vector<T, 3> {
+    Vector::from([force[0] / mb[0], force[1] / mb[1], force[2] / mb[2]])
+}
+
+/* (R^T * g, R^T * m) */
+fn body_references<T: Float + Default + AddAssign>(q: &Vector<T, 4>) -> (Vector<T, 3>, Vector<T, 3>) {
+    let gravity_r: Vector<T, 3> = Vector::from([
+        T::from(GRAVITY_R[0]).unwrap(),
+        T::from(GRAVITY_R[1]).unwrap(),
+        T::from(GRAVITY_R[2]).unwrap(),
+    ]);
+    let geomag_r: Vector<T, 3> = Vector::from([
+        T::from(GEOMAG_R[0]).unwrap(),
+        T::from(GEOMAG_R[1]).unwrap(),
+        T::from(GEOMAG_R[2]).unwrap(),
+    ]);
+    let dcm: DirectionCosineMatrix<T> = DirectionCosineMatrix::from(q);
+    (dcm.backward * gravity_r, dcm.backward * geomag_r)
+}
+
+/* sum of measured x estimated directions; zero vectors are ignored */
+fn direction_error<T: Float + Default + AddAssign>(q: &Vector<T, 4>, acc: &Vector<T, 3>, geomag: &Vector<T, 3>) -> Vector<T, 3> {
+    let (gravity_b, geomag_b) = body_references(q);
+    cross(&acc.normalize(), &gravity_b.normalize()) + cross(&geomag.normalize(), &geomag_b.normalize())
+}
+
+/* dq = 0.5 * q * [0, omega] */
+fn quaternion_rate<T: Float + Default>(q: &Vector<T, 4>, omega: &Vector<T, 3>) -> Vector<T, 4> {
+    let half: T = T::from(0.5).unwrap();
+    let p: Vector<T, 4> = Vector::from([T::zero(), omega[0] * half, omega[1] * half, omega[2] * half]);
+    quaternion_product(q, &p)
+}
+
+fn quaternion_product<T: Float + Default>(p: &Vector<T, 4>, q: &Vector<T, 4>) -> Vector<T, 4> {
+    Vector::from([
+        p[0] * q[0] - p[1] * q[1] - p[2] * q[2] - p[3] * q[3],
+        p[0] * q[1] + p[1] * q[0] + p[2] * q[3] - p[3] * q[2],
+        p[0] * q[2] - p[1] * q[3] + p[2] * q[0] + p[3] * q[1],
+        p[0] * q[3] + p[1] * q[2] - p[2] * q[1] + p[3] * q[0],
+    ])
+}
+
+fn cross<T: Float + Default>(a: &Vector<T, 3>, b: &Vector<T, 3>) -> Vector<T, 3> {
+    Vector::from([
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ])
+}
+
+/* [v x] such that [v x] * w = v x w */
+fn skew<T: Float + Default>(v: &Vector<T, 3>) -> Matrix<T, 3, 3> {
+    Matrix::from([
+        [T::zero(), -v[2], v[1]],
+        [v[2], T::zero(), -v[0]],
+        [-v[1], v[0], T::zero()],
+    ])
+}
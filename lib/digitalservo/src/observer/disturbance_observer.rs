@@ -11,6 +11,7 @@ where
     ts: T,
     kt: T,
     jm: T,
+    bandwidth: T,
     g:  Vector<T, { ORDER + 1 }>,
     tu: Vector<T, { ORDER + 1 }>,
     ty: Vector<T, { ORDER + 1 }>,
@@ -25,13 +26,31 @@ where
     [(); ORDER + 2]:,
 {
     pub fn new(ts: T, kt: T, jm: T, bandwidth: T) -> Self {
+        let mut ret: Self = Self {
+            ts,
+            kt,
+            jm,
+            bandwidth,
+            g: Vector::new(),
+            tu: Vector::new(),
+            ty: Vector::new(),
+            tz: Matrix::new(),
+            py: Vector::new(),
+            py0_z1: T::zero(),
+        };
+        ret.design();
+        ret
+    }
+
+    /* the observer poles are kept at the multiple root -bandwidth */
+    fn design(&mut self) {
         /* gain vector which realizes multiple root */
         let pascal_coeff: [T; ORDER + 2] = pascal_triangle::<T, { ORDER + 2 }>();
         let mut coeff: Vector<T, { ORDER + 1 }> = Vector::new();
         for i in 0..(ORDER + 1) {
-            coeff[i] = pascal_coeff[i + 1] * bandwidth.powi(i as i32 + 1);
+            coeff[i] = pascal_coeff[i + 1] * self.bandwidth.powi(i as i32 + 1);
         }
-        let g: Vector<T, { ORDER + 1 }> = &coeff * jm;
+        self.g = coeff * self.jm;
 
         /* minimum-order state observer */
         /* see https://digitalservo.jp/library/linear-control-design/observer-design/minimal-order-observer/ */
@@ -40,40 +59,28 @@ where
         let a_11: Matrix<T, { ORDER + 1 }, { ORDER + 1 }> = get_jordan_block::<T, { ORDER + 1 }>(T::zero());
         let a_12: Vector<T, { ORDER + 1 }> = Vector::new();
         let mut a_21: Vector<T, { ORDER + 1 }> = Vector::new();
-        a_21[0] = T::one() / jm;
+        a_21[0] = T::one() / self.jm;
         let a_22: T = T::zero();
 
         //input vector
         let b1: Vector<T, { ORDER + 1 }> = Vector::new();
-        let b2: T = kt / jm;
+        let b2: T = self.kt / self.jm;
 
         //matrices for state observer
-        let tu: Vector<T, { ORDER + 1 }> = b1 - g * b2;
-        let ty: Vector<T, { ORDER + 1 }> = a_12 - g * a_22;
-        let tz: Matrix<T, { ORDER + 1 }, { ORDER + 1 }> = a_11 - g.outer(a_21);
-
-        //initialize
-        let py: Vector<T, { ORDER + 1 }> = Vector::new();
-        let py0_z1: T = T::zero();
-
-        Self {ts, kt, jm, g, tu, ty, tz, py, py0_z1}
+        self.tu = b1 - self.g * b2;
+        self.ty = a_12 - self.g * a_22;
+        self.tz = a_11 - self.g.outer(a_21);
     }
 
     pub fn set_kt(mut self, kt: T) -> Self {
         self.kt = kt;
-        self.tu = self.g * (-kt / self.jm);
+        self.design();
         self
     }
 
     pub fn jm(mut self, jm: T) -> Self {
-        let a_11: Matrix<T, { ORDER + 1 }, { ORDER + 1 }> = get_jordan_block::<T, { ORDER + 1 }>(T::zero());
-        let mut a_21: Vector<T, { ORDER + 1 }> = Vector::new();
-        a_21[0] = -T::one() / jm;
-
         self.jm = jm;
-        self.tu = self.g * (-self.kt / jm);
-        self.tz = a_11 + self.g.outer(a_21);
-
+        self.design();
         self
     }
 
@@ -86,6 +93,144 @@ where
     }
 }
 
+/* minimum-order observer on [velocity; disturbance] driven by the position measurement */
+#[derive(Debug, Clone, Copy)]
+pub struct PositionBased<T, const ORDER: usize>
+where
+    [(); ORDER + 2]:,
+{
+    ts: T,
+    kt: T,
+    jm: T,
+    bandwidth: T,
+    g:  Vector<T, { ORDER + 2 }>,
+    tu: Vector<T, { ORDER + 2 }>,
+    tz: Matrix<T, { ORDER + 2 }, { ORDER + 2 }>,
+    pz: Vector<T, { ORDER + 2 }>,
+    x1: Vector<T, { ORDER + 2 }>,
+}
+
+impl<T: Float + Default + AddAssign, const ORDER: usize> PositionBased<T, ORDER>
+where
+    [(); ORDER + 2]:,
+    [(); ORDER + 3]:,
+{
+    pub fn new(ts: T, kt: T, jm: T, bandwidth: T) -> Self {
+        let mut ret: Self = Self {
+            ts,
+            kt,
+            jm,
+            bandwidth,
+            g: Vector::new(),
+            tu: Vector::new(),
+            tz: Matrix::new(),
+            pz: Vector::new(),
+            x1: Vector::new(),
+        };
+        ret.design();
+        ret
+    }
+
+    /* the observer poles are kept at the multiple root -bandwidth */
+    fn design(&mut self) {
+        //system matrix (x1 = [velocity; disturbance], y = position)
+        let mut a_11: Matrix<T, { ORDER + 2 }, { ORDER + 2 }> = get_jordan_block::<T, { ORDER + 2 }>(T::zero());
+        a_11[0][1] = T::one() / self.jm;
+        let mut a_21: Vector<T, { ORDER + 2 }> = Vector::new();
+        a_21[0] = T::one();
+
+        //input vector
+        let mut b1: Vector<T, { ORDER + 2 }> = Vector::new();
+        b1[0] = self.kt / self.jm;
+
+        /* gain vector which realizes multiple root */
+        let pascal_coeff: [T; ORDER + 3] = pascal_triangle::<T, { ORDER + 3 }>();
+        for i in 0..(ORDER + 2) {
+            self.g[i] = pascal_coeff[i + 1] * self.bandwidth.powi(i as i32 + 1);
+        }
+        for i in 1..(ORDER + 2) {
+            self.g[i] = self.g[i] * self.jm;
+        }
+
+        //matrices for state observer (a_12 = 0, a_22 = 0, b2 = 0)
+        self.tu = b1;
+        self.tz = a_11 - self.g.outer(a_21);
+    }
+
+    pub fn set_kt(mut self, kt: T) -> Self {
+        self.kt = kt;
+        self.design();
+        self
+    }
+
+    pub fn jm(mut self, jm: T) -> Self {
+        self.jm = jm;
+        self.design();
+        self
+    }
+
+    /* velocity estimated on the way */
+    pub fn velocity(&self) -> T {
+        self.x1[0]
+    }
+
+    pub fn update(&mut self, i: T, x: T) -> T {
+        self.x1 = self.pz + self.g * x;
+        let u: Vector<T, { ORDER + 2 }> = self.tu * i + (self.tz * self.g) * x;
+        self.pz += (u + self.tz * self.pz) * self.ts;
+        -self.x1[1]
+    }
+}
+
+/* full-order observer on the disturbance driven by jm * acceleration - kt * i */
+#[derive(Debug, Clone, Copy)]
+pub struct AccelerationBased<T, const ORDER: usize>
+where
+    [(); ORDER + 1]:,
+{
+    ts: T,
+    kt: T,
+    jm: T,
+    l:  Vector<T, { ORDER + 1 }>,
+    a:  Matrix<T, { ORDER + 1 }, { ORDER + 1 }>,
+    px: Vector<T, { ORDER + 1 }>,
+}
+
+impl<T: Float + Default + AddAssign, const ORDER: usize> AccelerationBased<T, ORDER>
+where
+    [(); ORDER + 1]:,
+    [(); ORDER + 2]:,
+{
+    pub fn new(ts: T, kt: T, jm: T, bandwidth: T) -> Self {
+        /* gain vector which realizes multiple root */
+        let pascal_coeff: [T; ORDER + 2] = pascal_triangle::<T, { ORDER + 2 }>();
+        let mut l: Vector<T, { ORDER + 1 }> = Vector::new();
+        for i in 0..(ORDER + 1) {
+            l[i] = pascal_coeff[i + 1] * bandwidth.powi(i as i32 + 1);
+        }
+        let a: Matrix<T, { ORDER + 1 }, { ORDER + 1 }> = get_jordan_block::<T, { ORDER + 1 }>(T::zero());
+
+        Self {ts, kt, jm, l, a, px: Vector::new()}
+    }
+
+    pub fn set_kt(mut self, kt: T) -> Self {
+        self.kt = kt;
+        self
+    }
+
+    pub fn jm(mut self, jm: T) -> Self {
+        self.jm = jm;
+        self
+    }
+
+    pub fn update(&mut self, i: T, acc: T) -> T {
+        let y: T = self.jm * acc - self.kt * i;
+        let u: Vector<T, { ORDER + 1 }> = self.a * self.px + self.l * (y - self.px[0]);
+        self.px += u * self.ts;
+        -self.px[0]
+    }
+}
+
 fn get_jordan_block<T: Float + Default, const ORDER: usize>(lambda: T) -> Matrix<T, ORDER, ORDER> {
     let mut ret: Matrix<T, ORDER, ORDER> = Matrix::<T, ORDER, ORDER>::new();
     for i in 0..ORDER - 1 {
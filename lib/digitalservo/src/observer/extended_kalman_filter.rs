@@ -0,0 +1,103 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use num_traits::Float;
+
+/* x[k+1] = f(x[k], u[k]) + w[k], y[k] = h(x[k]) + v[k], E[w * w^T] = Q, E[v * v^T] = R */
+/* f_jacobian and h_jacobian return df/dx and dh/dx */
+pub struct ExtendedKalmanFilter<T, F, FJ, H, HJ, const N: usize, const M: usize, const P: usize> {
+    f: F,
+    f_jacobian: FJ,
+    h: H,
+    h_jacobian: HJ,
+    q: Matrix<T, N, N>,
+    r: Matrix<T, P, P>,
+    x: Vector<T, N>,
+    covariance: Matrix<T, N, N>,
+    gain: Matrix<T, N, P>,
+}
+
+impl<T, F, FJ, H, HJ, const N: usize, const M: usize, const P: usize> ExtendedKalmanFilter<T, F, FJ, H, HJ, N, M, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    F: Fn(&[T; N], &[T; M]) -> [T; N],
+    FJ: Fn(&[T; N], &[T; M]) -> Matrix<T, N, N>,
+    H: Fn(&[T; N]) -> [T; P],
+    HJ: Fn(&[T; N]) -> Matrix<T, P, N>,
+{
+    /* the covariance starts from the identity */
+    pub fn new(f: F, f_jacobian: FJ, h: H, h_jacobian: HJ, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) -> Self {
+        Self {
+            f,
+            f_jacobian,
+            h,
+            h_jacobian,
+            q: *q,
+            r: *r,
+            x: Vector::new(),
+            covariance: Matrix::diag(T::one()),
+            gain: Matrix::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_initial_state(mut self, x: &[T; N]) -> Self {
+        self.x = Vector::from(x);
+        self
+    }
+
+    #[must_use]
+    pub fn set_initial_covariance(mut self, covariance: &Matrix<T, N, N>) -> Self {
+        self.covariance = *covariance;
+        self
+    }
+
+    pub fn set_noise_covariance(&mut self, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) {
+        self.q = *q;
+        self.r = *r;
+    }
+
+    pub fn state(&self) -> [T; N] {
+        self.x.data
+    }
+
+    pub fn covariance(&self) -> Matrix<T, N, N> {
+        self.covariance
+    }
+
+    /* gain used in the latest correction */
+    pub fn gain(&self) -> Matrix<T, N, P> {
+        self.gain
+    }
+
+    /* time update with the input u[k] */
+    pub fn predict(&mut self, u: &[T; M]) {
+        let f_x: Matrix<T, N, N> = (self.f_jacobian)(&self.x.data, u);
+        self.x = Vector::from((self.f)(&self.x.data, u));
+        self.covariance = (f_x * self.covariance * f_x.transpose() + self.q).symmetrize();
+    }
+
+    /* measurement update; returns None if the innovation covariance is singular */
+    pub fn correct(&mut self, y: &[T; P]) -> Option<[T; N]> {
+        let h_x: Matrix<T, P, N> = (self.h_jacobian)(&self.x.data);
+        let h_x_t: Matrix<T, N, P> = h_x.transpose();
+        let innovation: Matrix<T, P, P> = h_x * self.covariance * h_x_t + self.r;
+        let innovation_inv: Matrix<T, P, P> = innovation.inverse()?;
+        self.gain = self.covariance * h_x_t * innovation_inv;
+
+        let y_est: Vector<T, P> = Vector::from((self.h)(&self.x.data));
+        self.x += self.gain * (Vector::from(y) - y_est);
+
+        //Joseph form keeps the covariance positive semidefinite
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let ikh: Matrix<T, N, N> = identity - self.gain * h_x;
+        self.covariance = (ikh * self.covariance * ikh.transpose() + self.gain * self.r * self.gain.transpose()).symmetrize();
+        Some(self.x.data)
+    }
+
+    /* predict with the input applied over the last sample, then correct with the current output */
+    pub fn update(&mut self, u: &[T; M], y: &[T; P]) -> Option<[T; N]> {
+        self.predict(u);
+        self.correct(y)
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::AddAssign;
+
+use crate::algebra::*;
+use crate::combinatorics::*;
+use num_traits::Float;
+
+/* y^(N) = b0 * u + f, z = [y, y', ..., y^(N-1), f] */
+#[derive(Debug, Clone, Copy)]
+pub struct ExtendedStateObserver<T, const N: usize>
+where
+    [(); N + 1]:,
+{
+    ts: T,
+    b0: T,
+    l: Vector<T, { N + 1 }>,
+    z: Vector<T, { N + 1 }>,
+    fal: Option<(T, T)>,
+}
+
+impl<T: Float + Default + AddAssign, const N: usize> ExtendedStateObserver<T, N>
+where
+    [(); N + 1]:,
+{
+    pub fn new(ts: T, b0: T, bandwidth: T) -> Self {
+        //N = 0 has no output to observe
+        if N == 0 {
+            panic!("observer setting error: N must be positive.")
+        }
+        Self {
+            ts,
+            b0,
+            l: bandwidth_gain(bandwidth),
+            z: Vector::new(),
+            fal: None,
+        }
+    }
+
+    /* all the observer poles at -bandwidth: l_i = C(N + 1, i + 1) * bandwidth^(i + 1) */
+    #[must_use]
+    pub fn set_bandwidth(mut self, bandwidth: T) -> Self {
+        self.l = bandwidth_gain(bandwidth);
+        self
+    }
+
+    #[must_use]
+    pub fn set_gain(mut self, l: &[T; N + 1]) -> Self {
+        self.l = Vector::from(l);
+        self
+    }
+
+    #[must_use]
+    pub fn set_b0(mut self, b0: T) -> Self {
+        self.b0 = b0;
+        self
+    }
+
+    /* nonlinear ESO: the i-th correction is l_i * fal(e, alpha^i, delta) */
+    #[must_use]
+    pub fn set_fal(mut self, alpha: T, delta: T) -> Self {
+        self.fal = Some((alpha, delta));
+        self
+    }
+
+    pub fn b0(&self) -> T {
+        self.b0
+    }
+
+    pub fn state(&self) -> [T; N + 1] {
+        self.z.data
+    }
+
+    /* estimated total disturbance f */
+    pub fn disturbance(&self) -> T {
+        self.z[N]
+    }
+
+    pub fn reset(&mut self) {
+        self.z = Vector::new();
+    }
+
+    pub fn update(&mut self, u: T, y: T) -> [T; N + 1] {
+        let e: T = y - self.z[0];
+        let mut dz: Vector<T, { N + 1 }> = Vector::new();
+        let mut alpha: T = T::one();
+        for i in 0..(N + 1) {
+            let correction: T = match self.fal {
+                Some((a, delta)) => {
+                    let x: T = fal(e, alpha, delta);
+                    alpha = alpha * a;
+                    x
+                }
+                None => e,
+            };
+            dz[i] = self.l[i] * correction;
+            if i < N {
+                dz[i] += self.z[i + 1];
+            }
+        }
+        dz[N - 1] += self.b0 * u;
+        self.z += dz * self.ts;
+        self.z.data
+    }
+}
+
+fn bandwidth_gain<T: Float + Default, const N: usize>(bandwidth: T) -> Vector<T, N> {
+    let mut ret: Vector<T, N> = Vector::new();
+    for i in 0..N {
+        ret[i] = T::from(combination(N, i + 1)).unwrap() * bandwidth.powi(i as i32 + 1);
+    }
+    ret
+}
+
+/* |e|^alpha * sgn(e), linear within |e| <= delta */
+pub fn fal<T: Float>(e: T, alpha: T, delta: T) -> T {
+    if e.abs() <= delta {
+        e / delta.powf(T::one() - alpha)
+    } else {
+        e.abs().powf(alpha) * e.signum()
+    }
+}
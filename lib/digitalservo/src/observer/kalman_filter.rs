@@ -0,0 +1,130 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::riccati_equation::*;
+use crate::algebra::*;
+use crate::state_space::mimo::discrete;
+use num_traits::Float;
+
+/* x[k+1] = A * x[k] + B * u[k] + w[k], y[k] = C * x[k] + D * u[k] + v[k], E[w * w^T] = Q, E[v * v^T] = R */
+#[derive(Debug, Clone, Copy)]
+pub struct KalmanFilter<T, const N: usize, const M: usize, const P: usize> {
+    ssr: discrete::SSR<T, N, M, P>,
+    q: Matrix<T, N, N>,
+    r: Matrix<T, P, P>,
+    x: Vector<T, N>,
+    u: Vector<T, M>,
+    covariance: Matrix<T, N, N>,
+    gain: Matrix<T, N, P>,
+    steady_state: bool,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    KalmanFilter<T, N, M, P>
+{
+    /* time-varying filter; the covariance starts from the identity */
+    pub fn new<S: Into<discrete::SSR<T, N, M, P>>>(ssr: S, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) -> Self {
+        Self {
+            ssr: ssr.into(),
+            q: *q,
+            r: *r,
+            x: Vector::new(),
+            u: Vector::new(),
+            covariance: Matrix::diag(T::one()),
+            gain: Matrix::new(),
+            steady_state: false,
+        }
+    }
+
+    /* the gain and the covariance are fixed to the solution of the filter Riccati equation */
+    pub fn steady_state<S: Into<discrete::SSR<T, N, M, P>>>(ssr: S, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) -> Result<Self, EquationError> {
+        let mut ret: Self = Self::new(ssr, q, r);
+
+        //the predicted covariance solves the dual of the control Riccati equation
+        let a_t: Matrix<T, N, N> = ret.ssr.a.transpose();
+        let c_t: Matrix<T, N, P> = ret.ssr.c.transpose();
+        let predicted: Matrix<T, N, N> = discrete_algebraic_riccati_equation(&a_t, &c_t, q, r)?;
+        let innovation: Matrix<T, P, P> = ret.ssr.c * predicted * c_t + *r;
+        let innovation_inv: Matrix<T, P, P> = innovation.inverse().ok_or(EquationError::SingularWeight)?;
+
+        ret.gain = predicted * c_t * innovation_inv;
+        ret.covariance = ret.joseph_update(&predicted);
+        ret.steady_state = true;
+        Ok(ret)
+    }
+
+    #[must_use]
+    pub fn set_initial_state(mut self, x: &[T; N]) -> Self {
+        self.x = Vector::from(x);
+        self
+    }
+
+    /* ignored by the steady-state filter */
+    #[must_use]
+    pub fn set_initial_covariance(mut self, covariance: &Matrix<T, N, N>) -> Self {
+        if !self.steady_state {
+            self.covariance = *covariance;
+        }
+        self
+    }
+
+    pub fn state(&self) -> [T; N] {
+        self.x.data
+    }
+
+    pub fn covariance(&self) -> Matrix<T, N, N> {
+        self.covariance
+    }
+
+    /* gain used in the latest correction */
+    pub fn gain(&self) -> Matrix<T, N, P> {
+        self.gain
+    }
+
+    pub fn is_steady_state(&self) -> bool {
+        self.steady_state
+    }
+
+    /* time update with the input u[k] */
+    pub fn predict(&mut self, u: &[T; M]) {
+        self.u = Vector::from(u);
+        self.x = self.ssr.a * self.x + self.ssr.b * self.u;
+        if !self.steady_state {
+            self.covariance = (self.ssr.a * self.covariance * self.ssr.a.transpose() + self.q).symmetrize();
+        }
+    }
+
+    /* measurement update; the feedthrough term uses the input of the latest prediction */
+    pub fn correct(&mut self, y: &[T; P]) -> [T; N] {
+        if !self.steady_state {
+            let c_t: Matrix<T, N, P> = self.ssr.c.transpose();
+            let innovation: Matrix<T, P, P> = self.ssr.c * self.covariance * c_t + self.r;
+            //the covariance is kept as it is if the innovation is singular
+            if let Some(innovation_inv) = innovation.inverse() {
+                self.gain = self.covariance * c_t * innovation_inv;
+                self.covariance = self.joseph_update(&self.covariance);
+            }
+        }
+
+        let y_est: Vector<T, P> = self.ssr.c * self.x + self.ssr.d * self.u;
+        self.x += self.gain * (Vector::from(y) - y_est);
+        self.x.data
+    }
+
+    /* predict with the input applied over the last sample, then correct with the current output */
+    pub fn update(&mut self, u: &[T; M], y: &[T; P]) -> [T; N] {
+        self.predict(u);
+        self.correct(y)
+    }
+
+    pub fn reset(&mut self) {
+        self.x = Vector::new();
+        self.u = Vector::new();
+    }
+
+    /* (I - K * C) * P * (I - K * C)^T + K * R * K^T */
+    fn joseph_update(&self, predicted: &Matrix<T, N, N>) -> Matrix<T, N, N> {
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let ikc: Matrix<T, N, N> = identity - self.gain * self.ssr.c;
+        (ikc * *predicted * ikc.transpose() + self.gain * self.r * self.gain.transpose()).symmetrize()
+    }
+}
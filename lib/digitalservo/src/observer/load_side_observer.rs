@@ -0,0 +1,62 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use super::minimum_order_observer::MinimumOrderObserver;
+use crate::plant::two_inertia::TwoInertiaParameter;
+use crate::state_space::continuous;
+use num_complex::Complex;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoadSideEstimate<T> {
+    pub motor_velocity: T,
+    pub torsion: T,
+    pub load_velocity: T,
+    pub load_disturbance: T,
+}
+
+/* minimum-order observer of the two-inertia system x = [wm, torsion, wl, load disturbance] from the motor velocity wm */
+#[derive(Debug, Clone, Copy)]
+pub struct LoadSideObserver<T> {
+    kt: T,
+    observer: MinimumOrderObserver<T, 4>,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign> LoadSideObserver<T> {
+    /* observer poles at the multiple root -bandwidth; returns None if the system is not observable */
+    pub fn new(ts: T, kt: T, param: &TwoInertiaParameter<T>, bandwidth: T) -> Option<Self> {
+        Self::with_poles(ts, kt, param, &[Complex::new(-bandwidth, T::zero()); 3])
+    }
+
+    pub fn with_poles(ts: T, kt: T, param: &TwoInertiaParameter<T>, poles: &[Complex<T>; 3]) -> Option<Self> {
+        let observer: MinimumOrderObserver<T, 4> = MinimumOrderObserver::new(&two_inertia_model(param), poles, ts)?;
+        Some(Self { kt, observer })
+    }
+
+    pub fn reset(&mut self) {
+        self.observer.reset();
+    }
+
+    pub fn update(&mut self, i: T, wm: T) -> LoadSideEstimate<T> {
+        let x: [T; 4] = self.observer.update(self.kt * i, wm);
+        LoadSideEstimate {
+            motor_velocity: x[0],
+            torsion: x[1],
+            load_velocity: x[2],
+            load_disturbance: x[3],
+        }
+    }
+}
+
+/* torsion' = ks * (wm - wl) + ds * (wm' - wl') */
+pub fn two_inertia_model<T: Float + Default>(param: &TwoInertiaParameter<T>) -> continuous::SSR<T, 4> {
+    let (jm, jl, ks, ds) = (param.jm, param.jl, param.ks, param.ds);
+    let a: [[T; 4]; 4] = [
+        [T::zero(), -T::one() / jm, T::zero(), T::zero()],
+        [ks, -ds * (T::one() / jm + T::one() / jl), -ks, ds / jl],
+        [T::zero(), T::one() / jl, T::zero(), -T::one() / jl],
+        [T::zero(), T::zero(), T::zero(), T::zero()],
+    ];
+    let b: [T; 4] = [T::one() / jm, ds / jm, T::zero(), T::zero()];
+    let c: [T; 4] = [T::one(), T::zero(), T::zero(), T::zero()];
+    continuous::SSR::new(&a, &b, &c)
+}
@@ -0,0 +1,145 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use crate::state_space::{continuous, pole_placement};
+use num_complex::Complex;
+use num_traits::Float;
+
+/* Gopinath's minimum-order observer for the single measured output y = C * x */
+/* see https://digitalservo.jp/library/linear-control-design/observer-design/minimal-order-observer/ */
+#[derive(Debug, Clone, Copy)]
+pub struct MinimumOrderObserver<T, const N: usize>
+where
+    [(); N - 1]:,
+{
+    ts: T,
+    g: Vector<T, { N - 1 }>,
+    tu: Vector<T, { N - 1 }>,
+    ty: Vector<T, { N - 1 }>,
+    tz: Matrix<T, { N - 1 }, { N - 1 }>,
+    t_inv: Matrix<T, N, N>,
+    z: Vector<T, { N - 1 }>,
+}
+
+/* system partitioned into the unmeasured states x1 and the measured output y */
+struct Partition<T, const N: usize>
+where
+    [(); N - 1]:,
+{
+    a_11: Matrix<T, { N - 1 }, { N - 1 }>,
+    a_12: Vector<T, { N - 1 }>,
+    a_21: Vector<T, { N - 1 }>,
+    a_22: T,
+    b1: Vector<T, { N - 1 }>,
+    b2: T,
+    t_inv: Matrix<T, N, N>,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> MinimumOrderObserver<T, N>
+where
+    [(); N - 1]:,
+{
+    /* eig(A_11 - G * A_21) = poles; returns None if the unmeasured states are not observable */
+    pub fn new(ssr: &continuous::SSR<T, N>, poles: &[Complex<T>; N - 1], ts: T) -> Option<Self> {
+        let p: Partition<T, N> = partition(ssr)?;
+        let g: Vector<T, { N - 1 }> = pole_placement::bass_gura(&p.a_11.transpose(), &p.a_21, poles)?;
+        Some(Self::from_partition(&p, g, ts))
+    }
+
+    /* observer with the given gain G, e.g. the one which realizes a multiple root */
+    pub fn from_gain(ssr: &continuous::SSR<T, N>, g: &Vector<T, { N - 1 }>, ts: T) -> Option<Self> {
+        let p: Partition<T, N> = partition(ssr)?;
+        Some(Self::from_partition(&p, *g, ts))
+    }
+
+    fn from_partition(p: &Partition<T, N>, g: Vector<T, { N - 1 }>, ts: T) -> Self {
+        //z = x1 - G * y
+        let tu: Vector<T, { N - 1 }> = p.b1 - g * p.b2;
+        let ty: Vector<T, { N - 1 }> = p.a_12 - g * p.a_22;
+        let tz: Matrix<T, { N - 1 }, { N - 1 }> = p.a_11 - g.outer(p.a_21);
+
+        Self {
+            ts,
+            g,
+            tu,
+            ty,
+            tz,
+            t_inv: p.t_inv,
+            z: Vector::new(),
+        }
+    }
+
+    pub fn gain(&self) -> Vector<T, { N - 1 }> {
+        self.g
+    }
+
+    pub fn reset(&mut self) {
+        self.z = Vector::new();
+    }
+
+    /* returns the estimate of the full state x */
+    pub fn update(&mut self, u: T, y: T) -> [T; N] {
+        let x1: Vector<T, { N - 1 }> = self.z + self.g * y;
+        self.z += (self.tz * self.z + (self.tz * self.g + self.ty) * y + self.tu * u) * self.ts;
+
+        let mut x_bar: Vector<T, N> = Vector::new();
+        for i in 0..(N - 1) {
+            x_bar[i] = x1[i];
+        }
+        x_bar[N - 1] = y;
+        (self.t_inv * x_bar).data
+    }
+}
+
+/* [x1; y] = T * x; if C picks a single state, x1 is the rest of the states in order */
+fn partition<T, const N: usize>(ssr: &continuous::SSR<T, N>) -> Option<Partition<T, N>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N - 1]:,
+{
+    let mut t: Matrix<T, N, N> = Matrix::new();
+    let nonzero: Vec<usize> = (0..N).filter(|&i| ssr.c[i] != T::zero()).collect();
+    match nonzero.len() {
+        0 => return None,
+        1 => {
+            for (row, i) in (0..N).filter(|&i| i != nonzero[0]).enumerate() {
+                t[row][i] = T::one();
+            }
+        }
+        _ => {
+            let mut c: Matrix<T, 1, N> = Matrix::new();
+            c[0] = ssr.c.data;
+            for (row, v) in c.null_space().iter().enumerate() {
+                t[row] = v.data;
+            }
+        }
+    }
+    t[N - 1] = ssr.c.data;
+
+    let t_inv: Matrix<T, N, N> = t.inverse()?;
+    let a: Matrix<T, N, N> = t * ssr.a * t_inv;
+    let b: Vector<T, N> = t * ssr.b;
+
+    let mut a_11: Matrix<T, { N - 1 }, { N - 1 }> = Matrix::new();
+    let mut a_12: Vector<T, { N - 1 }> = Vector::new();
+    let mut a_21: Vector<T, { N - 1 }> = Vector::new();
+    let mut b1: Vector<T, { N - 1 }> = Vector::new();
+    for i in 0..(N - 1) {
+        for j in 0..(N - 1) {
+            a_11[i][j] = a[i][j];
+        }
+        a_12[i] = a[i][N - 1];
+        a_21[i] = a[N - 1][i];
+        b1[i] = b[i];
+    }
+
+    Some(Partition {
+        a_11,
+        a_12,
+        a_21,
+        a_22: a[N - 1][N - 1],
+        b1,
+        b2: b[N - 1],
+        t_inv,
+    })
+}
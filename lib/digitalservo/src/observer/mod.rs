@@ -1,2 +1,10 @@
+pub mod attitude_estimator;
 pub mod disturbance_observer;
+pub mod extended_kalman_filter;
+pub mod extended_state_observer;
+pub mod kalman_filter;
+pub mod load_side_observer;
+pub mod minimum_order_observer;
 pub mod quaternion_observer;
+pub mod reaction_force_observer;
+pub mod unscented_kalman_filter;
@@ -0,0 +1,154 @@
+use std::ops::AddAssign;
+
+use super::disturbance_observer::VelocityBased;
+use num_traits::Float;
+
+/* f(v) = (fc + (fs - fc) * exp(-(v / vs)^2)) * sgn(v) + b * v */
+#[derive(Debug, Clone, Copy)]
+pub struct FrictionModel<T> {
+    pub coulomb: T,
+    pub viscous: T,
+    pub static_friction: T,
+    pub stribeck_velocity: T,
+}
+
+impl<T: Float> FrictionModel<T> {
+    /* Coulomb and viscous friction without the Stribeck effect */
+    pub fn new(coulomb: T, viscous: T) -> Self {
+        Self {
+            coulomb,
+            viscous,
+            static_friction: coulomb,
+            stribeck_velocity: T::one(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_stribeck(mut self, static_friction: T, stribeck_velocity: T) -> Self {
+        if stribeck_velocity <= T::zero() || stribeck_velocity.is_nan() {
+            panic!("observer setting error: stribeck_velocity must be positive.")
+        }
+        self.static_friction = static_friction;
+        self.stribeck_velocity = stribeck_velocity;
+        self
+    }
+
+    /* friction beyond the Coulomb level at low velocity */
+    pub fn stribeck(&self, v: T) -> T {
+        let r: T = v / self.stribeck_velocity;
+        (self.static_friction - self.coulomb) * (-r * r).exp() * sign(v)
+    }
+
+    pub fn torque(&self, v: T) -> T {
+        self.coulomb * sign(v) + self.viscous * v + self.stribeck(v)
+    }
+}
+
+impl<T: Float> Default for FrictionModel<T> {
+    fn default() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+/* reaction force = disturbance - friction(v) - mgl * sin(x + offset) */
+/* the model torques are fed to the disturbance observer so that they pass the same filter */
+#[derive(Debug, Clone, Copy)]
+pub struct ReactionForceObserver<T, const ORDER: usize>
+where
+    [(); ORDER + 1]:,
+{
+    dob: VelocityBased<T, ORDER>,
+    kt: T,
+    jm: T,
+    friction: FrictionModel<T>,
+    gravity: T,
+    gravity_offset: T,
+}
+
+impl<T: Float + Default + AddAssign, const ORDER: usize> ReactionForceObserver<T, ORDER>
+where
+    [(); ORDER + 1]:,
+    [(); ORDER + 2]:,
+{
+    pub fn new(ts: T, kt: T, jm: T, bandwidth: T) -> Self {
+        Self {
+            dob: VelocityBased::new(ts, kt, jm, bandwidth),
+            kt,
+            jm,
+            friction: FrictionModel::default(),
+            gravity: T::zero(),
+            gravity_offset: T::zero(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_friction(mut self, friction: FrictionModel<T>) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /* mgl * sin(x + offset); x = -offset is the position hanging down */
+    #[must_use]
+    pub fn set_gravity(mut self, mgl: T, offset: T) -> Self {
+        self.gravity = mgl;
+        self.gravity_offset = offset;
+        self
+    }
+
+    pub fn set_kt(mut self, kt: T) -> Self {
+        self.kt = kt;
+        self.dob = self.dob.set_kt(kt);
+        self
+    }
+
+    pub fn jm(mut self, jm: T) -> Self {
+        self.jm = jm;
+        self.dob = self.dob.jm(jm);
+        self
+    }
+
+    pub fn friction(&self) -> FrictionModel<T> {
+        self.friction
+    }
+
+    pub fn gravity_torque(&self, x: T) -> T {
+        self.gravity * (x + self.gravity_offset).sin()
+    }
+
+    pub fn update(&mut self, i: T, v: T, x: T) -> T {
+        let model_torque: T = self.friction.torque(v) + self.gravity_torque(x);
+        self.dob.update(i - model_torque / self.kt, v)
+    }
+
+    /* phi of kt * i - stribeck(v) = [jm, viscous, coulomb, mgl] * phi for system_identification::{lsm, kalman_filter}::whitebox */
+    pub fn regressor(&self, acc: T, v: T, x: T) -> [T; 4] {
+        [acc, v, sign(v), (x + self.gravity_offset).sin()]
+    }
+
+    pub fn identification_output(&self, i: T, v: T) -> T {
+        self.kt * i - self.friction.stribeck(v)
+    }
+
+    /* parameter identified with regressor(): [jm, viscous, coulomb, mgl]; the Stribeck excess fs - fc is kept */
+    pub fn set_identified_parameter(&mut self, theta: &[T; 4]) {
+        if theta[0] != self.jm {
+            self.jm = theta[0];
+            self.dob = self.dob.jm(theta[0]);
+        }
+        self.friction.viscous = theta[1];
+        self.friction.static_friction = self.friction.static_friction - self.friction.coulomb + theta[2];
+        self.friction.coulomb = theta[2];
+        self.gravity = theta[3];
+    }
+}
+
+/* sgn(0) = 0 */
+fn sign<T: Float>(x: T) -> T {
+    if x > T::zero() {
+        T::one()
+    } else if x < T::zero() {
+        -T::one()
+    } else {
+        T::zero()
+    }
+}
@@ -0,0 +1,216 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use num_traits::Float;
+
+/* x[k+1] = f(x[k], u[k]) + w[k], y[k] = h(x[k]) + v[k], E[w * w^T] = Q, E[v * v^T] = R */
+pub struct UnscentedKalmanFilter<T, F, H, const N: usize, const M: usize, const P: usize> {
+    f: F,
+    h: H,
+    q: Matrix<T, N, N>,
+    r: Matrix<T, P, P>,
+    x: Vector<T, N>,
+    covariance: Matrix<T, N, N>,
+    gain: Matrix<T, N, P>,
+    alpha: T,
+    beta: T,
+    kappa: T,
+}
+
+/* x, x + sqrt((N + lambda) * P)_j, x - sqrt((N + lambda) * P)_j */
+struct SigmaPoints<T, const N: usize> {
+    center: Vector<T, N>,
+    plus: [Vector<T, N>; N],
+    minus: [Vector<T, N>; N],
+}
+
+/* weights of the center point (mean, covariance) and of the other points */
+struct Weights<T> {
+    mean_center: T,
+    covariance_center: T,
+    other: T,
+}
+
+impl<T, F, H, const N: usize, const M: usize, const P: usize> UnscentedKalmanFilter<T, F, H, N, M, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    F: Fn(&[T; N], &[T; M]) -> [T; N],
+    H: Fn(&[T; N]) -> [T; P],
+{
+    /* the covariance starts from the identity; (alpha, beta, kappa) = (1, 2, 0) */
+    pub fn new(f: F, h: H, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) -> Self {
+        Self {
+            f,
+            h,
+            q: *q,
+            r: *r,
+            x: Vector::new(),
+            covariance: Matrix::diag(T::one()),
+            gain: Matrix::new(),
+            alpha: T::one(),
+            beta: T::from(2.0).unwrap(),
+            kappa: T::zero(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_initial_state(mut self, x: &[T; N]) -> Self {
+        self.x = Vector::from(x);
+        self
+    }
+
+    #[must_use]
+    pub fn set_initial_covariance(mut self, covariance: &Matrix<T, N, N>) -> Self {
+        self.covariance = *covariance;
+        self
+    }
+
+    /* alpha: spread of the sigma points, beta: prior knowledge of the distribution (2 for Gaussian), kappa: secondary scaling */
+    #[must_use]
+    pub fn set_scaling(mut self, alpha: T, beta: T, kappa: T) -> Self {
+        //N + lambda = alpha^2 * (N + kappa) divides the weights
+        if alpha == T::zero() || alpha.is_nan() || T::from(N).unwrap() + kappa <= T::zero() {
+            panic!("observer setting error: alpha must be nonzero and N + kappa must be positive.")
+        }
+        self.alpha = alpha;
+        self.beta = beta;
+        self.kappa = kappa;
+        self
+    }
+
+    pub fn set_noise_covariance(&mut self, q: &Matrix<T, N, N>, r: &Matrix<T, P, P>) {
+        self.q = *q;
+        self.r = *r;
+    }
+
+    pub fn state(&self) -> [T; N] {
+        self.x.data
+    }
+
+    pub fn covariance(&self) -> Matrix<T, N, N> {
+        self.covariance
+    }
+
+    /* gain used in the latest correction */
+    pub fn gain(&self) -> Matrix<T, N, P> {
+        self.gain
+    }
+
+    fn lambda(&self) -> T {
+        let n: T = T::from(N).unwrap();
+        self.alpha * self.alpha * (n + self.kappa) - n
+    }
+
+    fn weights(&self) -> Weights<T> {
+        let lambda: T = self.lambda();
+        let scale: T = T::from(N).unwrap() + lambda;
+        let mean_center: T = lambda / scale;
+        Weights {
+            mean_center,
+            covariance_center: mean_center + T::one() - self.alpha * self.alpha + self.beta,
+            other: T::one() / (scale + scale),
+        }
+    }
+
+    /* returns None if the covariance is not positive definite */
+    fn sigma_points(&self) -> Option<SigmaPoints<T, N>> {
+        let scale: T = T::from(N).unwrap() + self.lambda();
+        let l: Matrix<T, N, N> = Eigen::cholesky_decomposition(self.covariance * scale)?.l;
+
+        let mut plus: [Vector<T, N>; N] = [self.x; N];
+        let mut minus: [Vector<T, N>; N] = [self.x; N];
+        for j in 0..N {
+            for i in 0..N {
+                plus[j][i] += l[i][j];
+                minus[j][i] -= l[i][j];
+            }
+        }
+        Some(SigmaPoints { center: self.x, plus, minus })
+    }
+
+    /* time update with the input u[k]; returns None if the covariance is not positive definite */
+    pub fn predict(&mut self, u: &[T; M]) -> Option<()> {
+        let w: Weights<T> = self.weights();
+        let chi: SigmaPoints<T, N> = self.sigma_points()?;
+
+        let center: Vector<T, N> = Vector::from((self.f)(&chi.center.data, u));
+        let mut plus: [Vector<T, N>; N] = [Vector::new(); N];
+        let mut minus: [Vector<T, N>; N] = [Vector::new(); N];
+        for j in 0..N {
+            plus[j] = Vector::from((self.f)(&chi.plus[j].data, u));
+            minus[j] = Vector::from((self.f)(&chi.minus[j].data, u));
+        }
+
+        let mut x: Vector<T, N> = center * w.mean_center;
+        for j in 0..N {
+            x += (plus[j] + minus[j]) * w.other;
+        }
+
+        let dx: Vector<T, N> = center - x;
+        let mut covariance: Matrix<T, N, N> = dx.outer(dx) * w.covariance_center + self.q;
+        for j in 0..N {
+            let dp: Vector<T, N> = plus[j] - x;
+            let dm: Vector<T, N> = minus[j] - x;
+            covariance += (dp.outer(dp) + dm.outer(dm)) * w.other;
+        }
+
+        self.x = x;
+        self.covariance = covariance.symmetrize();
+        Some(())
+    }
+
+    /* measurement update; returns None if the covariance is not positive definite or the innovation covariance is singular */
+    pub fn correct(&mut self, y: &[T; P]) -> Option<[T; N]> {
+        let w: Weights<T> = self.weights();
+        let chi: SigmaPoints<T, N> = self.sigma_points()?;
+
+        let center: Vector<T, P> = Vector::from((self.h)(&chi.center.data));
+        let mut plus: [Vector<T, P>; N] = [Vector::new(); N];
+        let mut minus: [Vector<T, P>; N] = [Vector::new(); N];
+        for j in 0..N {
+            plus[j] = Vector::from((self.h)(&chi.plus[j].data));
+            minus[j] = Vector::from((self.h)(&chi.minus[j].data));
+        }
+
+        let mut y_est: Vector<T, P> = center * w.mean_center;
+        for j in 0..N {
+            y_est += (plus[j] + minus[j]) * w.other;
+        }
+
+        let dy: Vector<T, P> = center - y_est;
+        let dx: Vector<T, N> = chi.center - self.x;
+        let mut innovation: Matrix<T, P, P> = outer(&dy, &dy) * w.covariance_center + self.r;
+        let mut cross: Matrix<T, N, P> = outer(&dx, &dy) * w.covariance_center;
+        for j in 0..N {
+            let dyp: Vector<T, P> = plus[j] - y_est;
+            let dym: Vector<T, P> = minus[j] - y_est;
+            let dxp: Vector<T, N> = chi.plus[j] - self.x;
+            let dxm: Vector<T, N> = chi.minus[j] - self.x;
+            innovation += (outer(&dyp, &dyp) + outer(&dym, &dym)) * w.other;
+            cross += (outer(&dxp, &dyp) + outer(&dxm, &dym)) * w.other;
+        }
+
+        let innovation_inv: Matrix<T, P, P> = innovation.inverse()?;
+        self.gain = cross * innovation_inv;
+        self.x += self.gain * (Vector::from(y) - y_est);
+        self.covariance = (self.covariance - self.gain * innovation * self.gain.transpose()).symmetrize();
+        Some(self.x.data)
+    }
+
+    /* predict with the input applied over the last sample, then correct with the current output */
+    pub fn update(&mut self, u: &[T; M], y: &[T; P]) -> Option<[T; N]> {
+        self.predict(u)?;
+        self.correct(y)
+    }
+}
+
+/* x * y^T for vectors of different dimensions */
+fn outer<T: Float + Default, const R: usize, const C: usize>(x: &Vector<T, R>, y: &Vector<T, C>) -> Matrix<T, R, C> {
+    let mut ret: Matrix<T, R, C> = Matrix::new();
+    for i in 0..R {
+        for j in 0..C {
+            ret[i][j] = x[i] * y[j];
+        }
+    }
+    ret
+}
@@ -18,6 +18,7 @@ pub struct SeriesLinkManipulator<T: Float> {
     pub kt: Vector<T, JOINTSPACE_DIM>,
     pub jm: Vector<T, JOINTSPACE_DIM>,
     pub link: Vector<T, JOINTSPACE_DIM>,
+    singular_threshold: Option<T>,
     ts: T,
 }
 
@@ -41,6 +42,7 @@ impl<T: Float + Default + std::ops::AddAssign + MulAssign> SeriesLinkManipulator
             kt: Vector::from(kt),
             jm: Vector::from(jm),
             link: Vector::from(link),
+            singular_threshold: None,
             ts,
         }
     }
@@ -118,6 +120,13 @@ impl<T: Float + Default + std::ops::AddAssign + MulAssign> SeriesLinkManipulator
         self
     }
 
+    /* singular values of the jacobian below the threshold are ignored in its pseudo-inverse */
+    #[must_use]
+    pub fn set_singular_threshold(mut self, threshold: T) -> Self {
+        self.singular_threshold = Some(threshold);
+        self
+    }
+
     pub fn update(&mut self, iq: [T; JOINTSPACE_DIM], dis: [T; JOINTSPACE_DIM]) {
         for i in 0..JOINTSPACE_DIM {
             self.d0theta[i] += self.d1theta[i] * self.ts;
@@ -136,7 +145,11 @@ impl<T: Float + Default + std::ops::AddAssign + MulAssign> SeriesLinkManipulator
         ddx_null: &[T; JOINTSPACE_DIM],
     ) -> [T; JOINTSPACE_DIM] {
         let djaco_dq: Vector<T, 2> = &self.djacobian * &self.d1theta;
-        let inv_jacobian: Matrix<T, 3, 2> = self.jacobian.inverse_underdetermined().unwrap();
+        let svd = self.jacobian.singular_value_decomposition();
+        let inv_jacobian: Matrix<T, 3, 2> = match self.singular_threshold {
+            Some(threshold) => svd.pseudo_inverse_with_tolerance(threshold),
+            None => svd.pseudo_inverse(),
+        };
 
         //Null space
         let identity_matrix: Matrix<T, 3, 3> = Matrix::diag(T::one());
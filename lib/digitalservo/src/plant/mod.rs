@@ -3,3 +3,4 @@ pub mod motor;
 pub mod pendulum;
 pub mod pmsm;
 pub mod rigid_body;
+pub mod two_inertia;
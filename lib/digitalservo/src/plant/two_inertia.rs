@@ -0,0 +1,81 @@
+use num_traits;
+
+/* motor inertia jm and load inertia jl coupled by a shaft with stiffness ks and damping ds */
+#[derive(Debug, Copy, Clone)]
+pub struct TwoInertiaParameter<T> {
+    pub jm: T,
+    pub jl: T,
+    pub ks: T,
+    pub ds: T,
+}
+
+impl<T: num_traits::Float> TwoInertiaParameter<T> {
+    pub fn new(jm: T, jl: T, ks: T) -> Self {
+        Self { jm, jl, ks, ds: T::zero() }
+    }
+
+    #[must_use]
+    pub fn set_ds(mut self, ds: T) -> Self {
+        self.ds = ds;
+        self
+    }
+
+    /* wr = sqrt(ks * (1 / jm + 1 / jl)) [rad/s] */
+    pub fn resonance_frequency(&self) -> T {
+        (self.ks * (T::one() / self.jm + T::one() / self.jl)).sqrt()
+    }
+
+    /* wa = sqrt(ks / jl) [rad/s] */
+    pub fn antiresonance_frequency(&self) -> T {
+        (self.ks / self.jl).sqrt()
+    }
+
+    /* jl / jm, which gives wr / wa = sqrt(1 + jl / jm) */
+    pub fn inertia_ratio(&self) -> T {
+        self.jl / self.jm
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Plant<T> {
+    pub d0xm: T,
+    pub d1xm: T,
+    pub d2xm: T,
+    pub d0xl: T,
+    pub d1xl: T,
+    pub d2xl: T,
+    pub torsion: T,
+    pub param: TwoInertiaParameter<T>,
+    pub ts: T,
+}
+
+impl<T> Plant<T>
+where
+    T: num_traits::Float + std::ops::AddAssign,
+{
+    pub fn new(ts: T, param: TwoInertiaParameter<T>) -> Self {
+        Self {
+            d0xm: T::zero(),
+            d1xm: T::zero(),
+            d2xm: T::zero(),
+            d0xl: T::zero(),
+            d1xl: T::zero(),
+            d2xl: T::zero(),
+            torsion: T::zero(),
+            param,
+            ts,
+        }
+    }
+
+    /* tau_m: motor torque, tau_l: load-side disturbance torque */
+    pub fn update(&mut self, tau_m: T, tau_l: T) {
+        self.d0xm += self.d1xm * self.ts;
+        self.d1xm += self.d2xm * self.ts;
+        self.d0xl += self.d1xl * self.ts;
+        self.d1xl += self.d2xl * self.ts;
+
+        self.torsion = self.param.ks * (self.d0xm - self.d0xl) + self.param.ds * (self.d1xm - self.d1xl);
+        self.d2xm = (tau_m - self.torsion) / self.param.jm;
+        self.d2xl = (self.torsion - tau_l) / self.param.jl;
+    }
+}
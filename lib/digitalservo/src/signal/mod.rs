@@ -4,4 +4,5 @@ pub mod freefilter;
 pub mod highpassfilter;
 pub mod integrator;
 pub mod lowpassfilter;
+pub mod notchfilter;
 pub mod stable_inversion;
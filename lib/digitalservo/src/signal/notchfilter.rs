@@ -0,0 +1,49 @@
+use num_traits;
+
+/* (s^2 + 2 * zeta_n * wn * s + wn^2) / (s^2 + 2 * zeta_d * wn * s + wn^2), Tustin transform prewarped at wn */
+#[derive(Debug, Copy, Clone)]
+pub struct NotchFilter<T> {
+    y_z1: T,
+    y_z2: T,
+    u_z1: T,
+    u_z2: T,
+    pub ts: T,
+    numer: [T; 3],
+    denom: [T; 2],
+}
+
+impl<T: num_traits::Float> NotchFilter<T> {
+    /* zeta_d sets the width and zeta_n / zeta_d the depth (0 for a full notch) */
+    pub fn new(ts: T, wn: T, zeta_n: T, zeta_d: T) -> Self {
+        let t2: T = T::from(2.0).unwrap();
+        let c: T = wn / (wn * ts / t2).tan();
+        let c2: T = c * c;
+        let wn2: T = wn * wn;
+
+        let a0: T = c2 + t2 * zeta_d * wn * c + wn2;
+        Self {
+            y_z1: T::zero(),
+            y_z2: T::zero(),
+            u_z1: T::zero(),
+            u_z2: T::zero(),
+            ts,
+            numer: [
+                (c2 + t2 * zeta_n * wn * c + wn2) / a0,
+                t2 * (wn2 - c2) / a0,
+                (c2 - t2 * zeta_n * wn * c + wn2) / a0,
+            ],
+            denom: [t2 * (wn2 - c2) / a0, (c2 - t2 * zeta_d * wn * c + wn2) / a0],
+        }
+    }
+
+    pub fn update(&mut self, u: T) -> T {
+        let out = self.numer[0] * u + self.numer[1] * self.u_z1 + self.numer[2] * self.u_z2
+            - self.denom[0] * self.y_z1
+            - self.denom[1] * self.y_z2;
+        self.y_z2 = self.y_z1;
+        self.y_z1 = out;
+        self.u_z2 = self.u_z1;
+        self.u_z1 = u;
+        out
+    }
+}
@@ -1,3 +1,5 @@
+use crate::algebra::*;
+use crate::state_space::continuous;
 use num_traits::Float;
 
 pub struct StableInverter<T, F1, const N: usize> {
@@ -75,3 +77,198 @@ impl<T: Float + std::ops::AddAssign, F1: Fn(T) -> [T; N], const N: usize> Stable
         x
     }
 }
+
+/* bounded state and input trajectories sampled at time[k] */
+#[derive(Debug, Clone)]
+pub struct InverseTrajectory<T, const N: usize> {
+    pub time: Vec<T>,
+    pub state: Vec<[T; N]>,
+    pub input: Vec<T>,
+}
+
+/* stable inversion of a SISO continuous system with hyperbolic zero dynamics. In the normal form z = [xi; eta], */
+/* xi = [y, y', ..., y^(r-1)] follows the reference and the internal state eta = eta_s + eta_u is split by the */
+/* spectral projectors of the zero dynamics: eta_s is integrated forward from 0 and eta_u backward from t_max */
+/* see S. Devasia, D. Chen and B. Paden, "Nonlinear inversion-based output tracking", 1996 */
+#[derive(Debug, Clone, Copy)]
+pub struct BatchStableInverter<T, const N: usize> {
+    r: usize,
+    t: Matrix<T, N, N>,
+    a_inv: Matrix<T, N, N>,
+    b_inv: Vector<T, N>,
+    zero_dynamics: Matrix<T, N, N>,
+    projector_stable: Matrix<T, N, N>,
+    projector_unstable: Matrix<T, N, N>,
+    output_row: Vector<T, N>,
+    output_gain: T,
+}
+
+impl<T, const N: usize> BatchStableInverter<T, N>
+where
+    T: Float + Default + std::ops::AddAssign + std::ops::SubAssign + std::ops::MulAssign,
+{
+    /* returns None if the input does not reach the output or the zero dynamics has poles on the imaginary axis */
+    pub fn new(ssr: &continuous::SSR<T, N>) -> Option<Self> {
+        //relative degree r, rows c * A^(i-1) (i = 1..r) and y^(r) = c * A^r * x + g * u
+        let mut rows: [Vector<T, N>; N] = [Vector::new(); N];
+        let mut output_row: Vector<T, N> = ssr.c;
+        let mut output_gain: T = ssr.d;
+        let mut r: usize = 0;
+        while output_gain == T::zero() {
+            if r == N {
+                return None;
+            }
+            rows[r] = output_row;
+            output_gain = output_row.dot(ssr.b);
+            output_row = ssr.a.transpose() * output_row;
+            r += 1;
+        }
+
+        //complete the rows to a basis, then remove the input from the internal dynamics: L * b = 0
+        let mut basis: [Vector<T, N>; N] = rows;
+        for (i, row) in rows.iter().enumerate().take(r) {
+            basis[i] = orthogonalize(&basis[..i], *row)?;
+        }
+        let mut n_basis: usize = r;
+        for j in 0..N {
+            if n_basis == N {
+                break;
+            }
+            let mut e: Vector<T, N> = Vector::new();
+            e[j] = T::one();
+            if let Some(v) = orthogonalize(&basis[..n_basis], e) {
+                basis[n_basis] = v;
+                rows[n_basis] = if r > 0 { v - rows[r - 1] * (v.dot(ssr.b) / output_gain) } else { v };
+                n_basis += 1;
+            }
+        }
+
+        let mut t_inv: Matrix<T, N, N> = Matrix::new();
+        for i in 0..N {
+            t_inv[i] = rows[i].data;
+        }
+        let t: Matrix<T, N, N> = t_inv.inverse()?;
+
+        //inverse system z' = a_inv * z + b_inv * y^(r) with u = (y^(r) - c * A^r * x) / g
+        let k: Matrix<T, N, N> = Matrix::from(std::array::from_fn(|i| (output_row * (ssr.b[i] / output_gain)).data));
+        let a_inv: Matrix<T, N, N> = t_inv * (ssr.a - k) * t;
+        let b_inv: Vector<T, N> = t_inv * ssr.b / output_gain;
+
+        //the output chain xi is given, so it is replaced by the decoupled block -I
+        let mut zero_dynamics: Matrix<T, N, N> = a_inv;
+        for i in 0..N {
+            for j in 0..N {
+                if i < r || j < r {
+                    zero_dynamics[i][j] = if i == j { -T::one() } else { T::zero() };
+                }
+            }
+        }
+        let sign: Matrix<T, N, N> = matrix_sign(&zero_dynamics)?;
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let t2: T = T::from(2.0).unwrap();
+
+        Some(Self {
+            r,
+            t,
+            a_inv,
+            b_inv,
+            zero_dynamics,
+            projector_stable: (identity - sign) / t2,
+            projector_unstable: (identity + sign) / t2,
+            output_row,
+            output_gain,
+        })
+    }
+
+    pub fn relative_degree(&self) -> usize {
+        self.r
+    }
+
+    /* reference(t) = [y, y', ..., y^(N)], of which the first r + 1 are used; trapezoidal rule with the step dt */
+    pub fn solve<F: Fn(T) -> [T; N + 1]>(&self, reference: F, t_max: T, dt: T) -> Option<InverseTrajectory<T, N>> {
+        let steps: usize = (t_max / dt).round().to_usize()?;
+        let time: Vec<T> = (0..steps + 1).map(|k| T::from(k).unwrap() * dt).collect();
+        let derivatives: Vec<[T; N + 1]> = time.iter().map(|&t| reference(t)).collect();
+
+        //internal dynamics driven by the output chain
+        let input: Vec<Vector<T, N>> = derivatives
+            .iter()
+            .map(|y| {
+                let mut xi: Vector<T, N> = Vector::new();
+                xi.data[..self.r].copy_from_slice(&y[..self.r]);
+                let mut g: Vector<T, N> = self.a_inv * xi + self.b_inv * y[self.r];
+                g.data[..self.r].fill(T::zero());
+                g
+            })
+            .collect();
+
+        let dt_h: T = dt / T::from(2.0).unwrap();
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let implicit: Matrix<T, N, N> = (identity - self.zero_dynamics * dt_h).inverse()?;
+        let explicit: Matrix<T, N, N> = identity + self.zero_dynamics * dt_h;
+        let implicit_backward: Matrix<T, N, N> = explicit.inverse()?;
+        let explicit_backward: Matrix<T, N, N> = identity - self.zero_dynamics * dt_h;
+
+        //forward sweep from eta_s(0) = 0 and backward sweep from eta_u(t_max) = 0
+        let mut eta: Vec<Vector<T, N>> = vec![Vector::new(); steps + 1];
+        let mut eta_s: Vector<T, N> = Vector::new();
+        for k in 0..steps {
+            let g: Vector<T, N> = self.projector_stable * (input[k] + input[k + 1]);
+            eta_s = self.projector_stable * (implicit * (explicit * eta_s + g * dt_h));
+            eta[k + 1] = eta_s;
+        }
+        let mut eta_u: Vector<T, N> = Vector::new();
+        for k in (0..steps).rev() {
+            let g: Vector<T, N> = self.projector_unstable * (input[k] + input[k + 1]);
+            eta_u = self.projector_unstable * (implicit_backward * (explicit_backward * eta_u - g * dt_h));
+            eta[k] += eta_u;
+        }
+
+        let mut state: Vec<[T; N]> = Vec::with_capacity(steps + 1);
+        let mut u: Vec<T> = Vec::with_capacity(steps + 1);
+        for k in 0..steps + 1 {
+            let mut z: Vector<T, N> = eta[k];
+            z.data[..self.r].copy_from_slice(&derivatives[k][..self.r]);
+            let x: Vector<T, N> = self.t * z;
+            u.push((derivatives[k][self.r] - self.output_row.dot(x)) / self.output_gain);
+            state.push(x.data);
+        }
+
+        Some(InverseTrajectory { time, state, input: u })
+    }
+}
+
+/* v minus its projection onto the orthonormal basis, normalized; None if v lies in its span */
+fn orthogonalize<T: Float + Default + std::ops::AddAssign, const N: usize>(
+    basis: &[Vector<T, N>],
+    v: Vector<T, N>,
+) -> Option<Vector<T, N>> {
+    let mut w: Vector<T, N> = v;
+    for e in basis {
+        w = w - *e * e.dot(w);
+    }
+    let norm: T = w.dot(w).sqrt();
+    if norm <= T::from(1e-9).unwrap() * v.dot(v).sqrt() {
+        return None;
+    }
+    Some(w / norm)
+}
+
+/* Newton iteration S = (S + S^-1) / 2, which converges unless an eigenvalue lies on the imaginary axis */
+fn matrix_sign<T, const N: usize>(m: &Matrix<T, N, N>) -> Option<Matrix<T, N, N>>
+where
+    T: Float + Default + std::ops::AddAssign + std::ops::SubAssign + std::ops::MulAssign,
+{
+    const MAX_ITERATION: usize = 100;
+    let t2: T = T::from(2.0).unwrap();
+    let mut s: Matrix<T, N, N> = *m;
+    for _ in 0..MAX_ITERATION {
+        let next: Matrix<T, N, N> = (s + s.inverse()?) / t2;
+        let converged: bool = (next - s).max_norm() <= T::from(1e-12).unwrap() * next.max_norm();
+        s = next;
+        if converged {
+            return Some(s);
+        }
+    }
+    None
+}
@@ -1,6 +1,7 @@
-use std::ops::AddAssign;
+use std::ops::{AddAssign, MulAssign, SubAssign};
 
 use crate::algebra::*;
+use num_complex::Complex;
 use num_traits::Float;
 
 #[derive(Debug, Copy, Clone)]
@@ -8,6 +9,7 @@ pub struct SSR<T, const N: usize> {
     pub a: Matrix<T, N, N>,
     pub b: Vector<T, N>,
     pub c: Vector<T, N>,
+    pub d: T,
 }
 
 impl<T: Float + Default, const N: usize> SSR<T, N> {
@@ -16,8 +18,27 @@ impl<T: Float + Default, const N: usize> SSR<T, N> {
             a: Matrix::from(a),
             b: Vector::from(b),
             c: Vector::from(c),
+            d: T::zero(),
         }
     }
+
+    #[must_use]
+    pub fn set_d(mut self, d: T) -> Self {
+        self.d = d;
+        self
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> SSR<T, N> {
+    pub fn poles(&self) -> Option<[Complex<T>; N]> {
+        self.a.eigenvalues()
+    }
+
+    /* all poles lie in the open left half plane */
+    pub fn is_stable(&self) -> Option<bool> {
+        let poles: [Complex<T>; N] = self.poles()?;
+        Some(poles.iter().all(|p| p.re < T::zero()))
+    }
 }
 
 pub struct Plant<T, const N: usize> {
@@ -40,6 +61,10 @@ impl<T: Float + Default + AddAssign, const N: usize> Plant<T, N> {
     pub fn update(&mut self, u: T) {
         let dx: Vector<T, N> = self.ssr.a * self.x + self.ssr.b * u;
         self.x += dx * self.ts;
-        self.y = self.ssr.c.dot(self.x);
+        self.y = self.ssr.c.dot(self.x) + self.ssr.d * u;
+    }
+
+    pub fn state(&self) -> [T; N] {
+        self.x.data
     }
 }
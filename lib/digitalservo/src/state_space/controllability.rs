@@ -0,0 +1,410 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::eigen::singular_value_decomposition::SVDMatrix;
+use crate::algebra::lyapunov_equation::*;
+use crate::algebra::*;
+use num_traits::Float;
+
+use super::{continuous, discrete, mimo};
+
+/* x = T * z, where z = [x_co_bar, x_co, x_c_bar_o_bar, x_c_bar_o] (c: controllable, o: observable) */
+#[derive(Debug, Clone, Copy)]
+pub struct KalmanDecomposition<T, const N: usize> {
+    pub t: Matrix<T, N, N>,
+    pub t_inv: Matrix<T, N, N>,
+    pub controllable_unobservable: usize,
+    pub controllable_observable: usize,
+    pub uncontrollable_unobservable: usize,
+    pub uncontrollable_observable: usize,
+}
+
+/* [B, A * B, ..., A^(N-1) * B] */
+fn controllability_matrix<T, const N: usize, const M: usize>(a: &Matrix<T, N, N>, b: &Matrix<T, N, M>) -> Matrix<T, N, { N * M }>
+where
+    T: Float + Default,
+    [(); N * M]:,
+{
+    let mut ret: Matrix<T, N, { N * M }> = Matrix::new();
+    let mut x: Matrix<T, N, M> = *b;
+    for k in 0..N {
+        for i in 0..N {
+            for j in 0..M {
+                ret[i][k * M + j] = x[i][j];
+            }
+        }
+        x = *a * x;
+    }
+    ret
+}
+
+/* [C; C * A; ...; C * A^(N-1)] */
+fn observability_matrix<T, const N: usize, const P: usize>(a: &Matrix<T, N, N>, c: &Matrix<T, P, N>) -> Matrix<T, { N * P }, N>
+where
+    T: Float + Default,
+    [(); N * P]:,
+{
+    let mut ret: Matrix<T, { N * P }, N> = Matrix::new();
+    let mut x: Matrix<T, P, N> = *c;
+    for k in 0..N {
+        for i in 0..P {
+            for j in 0..N {
+                ret[k * P + i][j] = x[i][j];
+            }
+        }
+        x = x * *a;
+    }
+    ret
+}
+
+fn projector<T: Float + Default + AddAssign, const N: usize>(basis: &[Vector<T, N>]) -> Matrix<T, N, N> {
+    let mut ret: Matrix<T, N, N> = Matrix::new();
+    for v in basis {
+        for i in 0..N {
+            for j in 0..N {
+                ret[i][j] += v[i] * v[j];
+            }
+        }
+    }
+    ret
+}
+
+fn kalman_decomposition<T, const N: usize, const K: usize, const L: usize>(
+    ctrb: &Matrix<T, N, K>,
+    obsv: &Matrix<T, L, N>,
+) -> Option<KalmanDecomposition<T, N>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+
+    //range of the controllability matrix and null space of the observability matrix
+    let ctrb: SVDMatrix<T, N, K> = ctrb.singular_value_decomposition();
+    let controllable: Vec<Vector<T, N>> = (0..ctrb.rank())
+        .map(|j| Vector::from(ctrb.u.transpose()[j]))
+        .collect();
+    let unobservable: Vec<Vector<T, N>> = obsv.null_space();
+
+    let p_c: Matrix<T, N, N> = projector(&controllable);
+    let p_o_bar: Matrix<T, N, N> = projector(&unobservable);
+
+    //the projectors carry rounding errors, so their sums are cut with a looser tolerance
+    let tolerance: T = T::from(N).unwrap() * T::epsilon().sqrt();
+    let null_space = |m: Matrix<T, N, N>| m.singular_value_decomposition().null_space_with_tolerance(tolerance);
+
+    //the intersection is the common null space of the complementary projectors
+    let co_bar: Vec<Vector<T, N>> = null_space((identity - p_c) + (identity - p_o_bar));
+    let p_co_bar: Matrix<T, N, N> = projector(&co_bar);
+    let co: Vec<Vector<T, N>> = null_space((identity - p_c) + p_co_bar);
+    let c_bar_o_bar: Vec<Vector<T, N>> = null_space((identity - p_o_bar) + p_co_bar);
+
+    let mut t: Matrix<T, N, N> = Matrix::new();
+    let mut filled: usize = 0;
+    for v in co_bar.iter().chain(co.iter()).chain(c_bar_o_bar.iter()) {
+        if filled == N {
+            return None;
+        }
+        for i in 0..N {
+            t[i][filled] = v[i];
+        }
+        filled += 1;
+    }
+
+    //the rest spans the orthogonal complement of the columns filled so far
+    let c_bar_o: Vec<Vector<T, N>> = null_space(t.transpose());
+    if filled + c_bar_o.len() != N {
+        return None;
+    }
+    for (k, v) in c_bar_o.iter().enumerate() {
+        for i in 0..N {
+            t[i][filled + k] = v[i];
+        }
+    }
+
+    Some(KalmanDecomposition {
+        t,
+        t_inv: t.inverse()?,
+        controllable_unobservable: co_bar.len(),
+        controllable_observable: co.len(),
+        uncontrollable_unobservable: c_bar_o_bar.len(),
+        uncontrollable_observable: c_bar_o.len(),
+    })
+}
+
+pub(crate) fn siso_controllability_matrix<T: Float + Default, const N: usize>(a: &Matrix<T, N, N>, b: &Vector<T, N>) -> Matrix<T, N, N> {
+    let mut ret: Matrix<T, N, N> = Matrix::new();
+    let mut x: Vector<T, N> = *b;
+    for j in 0..N {
+        for i in 0..N {
+            ret[i][j] = x[i];
+        }
+        x = *a * x;
+    }
+    ret
+}
+
+fn siso_observability_matrix<T: Float + Default, const N: usize>(a: &Matrix<T, N, N>, c: &Vector<T, N>) -> Matrix<T, N, N> {
+    let mut ret: Matrix<T, N, N> = Matrix::new();
+    let at: Matrix<T, N, N> = a.transpose();
+    let mut x: Vector<T, N> = *c;
+    for i in 0..N {
+        ret[i] = x.data;
+        x = at * x;
+    }
+    ret
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> continuous::SSR<T, N> {
+    pub fn controllability_matrix(&self) -> Matrix<T, N, N> {
+        siso_controllability_matrix(&self.a, &self.b)
+    }
+
+    pub fn observability_matrix(&self) -> Matrix<T, N, N> {
+        siso_observability_matrix(&self.a, &self.c)
+    }
+
+    pub fn controllability_rank(&self) -> usize {
+        self.controllability_matrix().rank()
+    }
+
+    pub fn observability_rank(&self) -> usize {
+        self.observability_matrix().rank()
+    }
+
+    pub fn is_controllable(&self) -> bool {
+        self.controllability_rank() == N
+    }
+
+    pub fn is_observable(&self) -> bool {
+        self.observability_rank() == N
+    }
+
+    pub fn kalman_decomposition(&self) -> Option<KalmanDecomposition<T, N>> {
+        kalman_decomposition(&self.controllability_matrix(), &self.observability_matrix())
+    }
+
+    /* A * Wc + Wc * A^T + B * B^T = 0; returns None if the system is not stable */
+    pub fn controllability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        continuous_lyapunov_equation(self.a, self.b.outer(self.b)).ok()
+    }
+
+    /* A^T * Wo + Wo * A + C^T * C = 0; returns None if the system is not stable */
+    pub fn observability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        continuous_lyapunov_equation(self.a.transpose(), self.c.outer(self.c)).ok()
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> discrete::SSR<T, N> {
+    pub fn controllability_matrix(&self) -> Matrix<T, N, N> {
+        siso_controllability_matrix(&self.a, &self.b)
+    }
+
+    pub fn observability_matrix(&self) -> Matrix<T, N, N> {
+        siso_observability_matrix(&self.a, &self.c)
+    }
+
+    pub fn controllability_rank(&self) -> usize {
+        self.controllability_matrix().rank()
+    }
+
+    pub fn observability_rank(&self) -> usize {
+        self.observability_matrix().rank()
+    }
+
+    pub fn is_controllable(&self) -> bool {
+        self.controllability_rank() == N
+    }
+
+    pub fn is_observable(&self) -> bool {
+        self.observability_rank() == N
+    }
+
+    pub fn kalman_decomposition(&self) -> Option<KalmanDecomposition<T, N>> {
+        kalman_decomposition(&self.controllability_matrix(), &self.observability_matrix())
+    }
+
+    /* A * Wc * A^T - Wc + B * B^T = 0; returns None if the system is not stable */
+    pub fn controllability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        discrete_lyapunov_equation(self.a, self.b.outer(self.b)).ok()
+    }
+
+    /* A^T * Wo * A - Wo + C^T * C = 0; returns None if the system is not stable */
+    pub fn observability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        discrete_lyapunov_equation(self.a.transpose(), self.c.outer(self.c)).ok()
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    mimo::continuous::SSR<T, N, M, P>
+{
+    pub fn controllability_matrix(&self) -> Matrix<T, N, { N * M }>
+    where
+        [(); N * M]:,
+    {
+        controllability_matrix(&self.a, &self.b)
+    }
+
+    pub fn observability_matrix(&self) -> Matrix<T, { N * P }, N>
+    where
+        [(); N * P]:,
+    {
+        observability_matrix(&self.a, &self.c)
+    }
+
+    pub fn controllability_rank(&self) -> usize
+    where
+        [(); N * M]:,
+    {
+        self.controllability_matrix().rank()
+    }
+
+    pub fn observability_rank(&self) -> usize
+    where
+        [(); N * P]:,
+    {
+        self.observability_matrix().rank()
+    }
+
+    pub fn is_controllable(&self) -> bool
+    where
+        [(); N * M]:,
+    {
+        self.controllability_rank() == N
+    }
+
+    pub fn is_observable(&self) -> bool
+    where
+        [(); N * P]:,
+    {
+        self.observability_rank() == N
+    }
+
+    pub fn kalman_decomposition(&self) -> Option<KalmanDecomposition<T, N>>
+    where
+        [(); N * M]:,
+        [(); N * P]:,
+    {
+        kalman_decomposition(&self.controllability_matrix(), &self.observability_matrix())
+    }
+
+    /* A * Wc + Wc * A^T + B * B^T = 0; returns None if the system is not stable */
+    pub fn controllability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        continuous_lyapunov_equation(self.a, self.b * self.b.transpose()).ok()
+    }
+
+    /* A^T * Wo + Wo * A + C^T * C = 0; returns None if the system is not stable */
+    pub fn observability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        continuous_lyapunov_equation(self.a.transpose(), self.c.transpose() * self.c).ok()
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    mimo::discrete::SSR<T, N, M, P>
+{
+    pub fn controllability_matrix(&self) -> Matrix<T, N, { N * M }>
+    where
+        [(); N * M]:,
+    {
+        controllability_matrix(&self.a, &self.b)
+    }
+
+    pub fn observability_matrix(&self) -> Matrix<T, { N * P }, N>
+    where
+        [(); N * P]:,
+    {
+        observability_matrix(&self.a, &self.c)
+    }
+
+    pub fn controllability_rank(&self) -> usize
+    where
+        [(); N * M]:,
+    {
+        self.controllability_matrix().rank()
+    }
+
+    pub fn observability_rank(&self) -> usize
+    where
+        [(); N * P]:,
+    {
+        self.observability_matrix().rank()
+    }
+
+    pub fn is_controllable(&self) -> bool
+    where
+        [(); N * M]:,
+    {
+        self.controllability_rank() == N
+    }
+
+    pub fn is_observable(&self) -> bool
+    where
+        [(); N * P]:,
+    {
+        self.observability_rank() == N
+    }
+
+    pub fn kalman_decomposition(&self) -> Option<KalmanDecomposition<T, N>>
+    where
+        [(); N * M]:,
+        [(); N * P]:,
+    {
+        kalman_decomposition(&self.controllability_matrix(), &self.observability_matrix())
+    }
+
+    /* A * Wc * A^T - Wc + B * B^T = 0; returns None if the system is not stable */
+    pub fn controllability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        discrete_lyapunov_equation(self.a, self.b * self.b.transpose()).ok()
+    }
+
+    /* A^T * Wo * A - Wo + C^T * C = 0; returns None if the system is not stable */
+    pub fn observability_gramian(&self) -> Option<Matrix<T, N, N>>
+    where
+        [(); N * N]:,
+    {
+        if !self.is_stable()? {
+            return None;
+        }
+        discrete_lyapunov_equation(self.a.transpose(), self.c.transpose() * self.c).ok()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::algebra::*;
+use num_complex::Complex;
 use num_traits::Float;
 
 use std::ops::{AddAssign, SubAssign, MulAssign};
@@ -10,6 +11,7 @@ pub struct SSR<T, const N: usize> {
     pub a: Matrix<T, N, N>,
     pub b: Vector<T, N>,
     pub c: Vector<T, N>,
+    pub d: T,
     pub ts: T,
 }
 
@@ -19,6 +21,7 @@ impl<T: Float + Default + AddAssign + SubAssign +  MulAssign, const N: usize> SS
             a: Matrix::new(),
             b: Vector::new(),
             c: Vector::new(),
+            d: T::zero(),
             ts,
         }
     }
@@ -28,26 +31,116 @@ impl<T: Float + Default + AddAssign + SubAssign +  MulAssign, const N: usize> SS
             a: Matrix::from(a),
             b: Vector::from(b),
             c: Vector::from(c),
+            d: T::zero(),
             ts,
         }
     }
 
+    #[must_use]
+    pub fn set_d(mut self, d: T) -> Self {
+        self.d = d;
+        self
+    }
+
+    /* zero-order hold: Ad = exp(A * ts), Bd = int_0^ts exp(A * t) dt * B */
     pub fn from_continuous_ssr(c_ssr: &continuous::SSR<T, N>, ts: T) -> Self {
-        const INT_DIV: usize = 100;
-        let mut a_int: Matrix<T, N, N> = Matrix::<T, N, N>::new();
-        let mut t: T = T::zero();
-        let tp: T = ts / T::from(INT_DIV).unwrap();
-        for _ in 0..INT_DIV {
-            let integrand: Matrix<T, N, N> = (c_ssr.a * t).exp();
-            a_int += integrand * tp;
-            t += tp;
-        }
+        let b: Matrix<T, N, 1> = Matrix::from(c_ssr.b.data.map(|x| [x]));
+        let (a, g1, _) = hold_integrals(&c_ssr.a, &b, ts);
+        let b: Vector<T, N> = Vector::from(g1.data.map(|x| x[0]));
+        Self { a, b, c: c_ssr.c, d: c_ssr.d, ts }
+    }
+
+    /* first-order (triangle) hold: G1 = int_0^ts exp(A * t) dt * B, G2 = int_0^ts (ts - t) * exp(A * t) dt * B / ts */
+    pub fn from_continuous_ssr_foh(c_ssr: &continuous::SSR<T, N>, ts: T) -> Self {
+        let b: Matrix<T, N, 1> = Matrix::from(c_ssr.b.data.map(|x| [x]));
+        let (a, g1, g2) = hold_integrals(&c_ssr.a, &b, ts);
+        let g1: Vector<T, N> = Vector::from(g1.data.map(|x| x[0]));
+        let g2: Vector<T, N> = Vector::from(g2.data.map(|x| x[0] / ts));
+
+        //the state is shifted by G2 * u so that the realization becomes causal
+        let b: Vector<T, N> = g1 + a * g2 - g2;
+        let d: T = c_ssr.d + c_ssr.c.dot(g2);
+
+        Self { a, b, c: c_ssr.c, d, ts }
+    }
+
+    /* bilinear transform s = (z - 1) / (alpha * (z + 1)); alpha = tan(w * ts / 2) / w if prewarped at w [rad/s] */
+    pub fn from_continuous_ssr_tustin(c_ssr: &continuous::SSR<T, N>, ts: T, prewarp: Option<T>) -> Self {
+        let t_05: T = T::from(0.5).unwrap();
+        let alpha: T = match prewarp {
+            Some(omega) if omega > T::zero() => (omega * ts * t_05).tan() / omega,
+            _ => ts * t_05,
+        };
+
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let m: Matrix<T, N, N> = (identity - c_ssr.a * alpha).inverse().unwrap();
+        let mb: Vector<T, N> = m * c_ssr.b;
+
+        let a: Matrix<T, N, N> = m * (identity + c_ssr.a * alpha);
+        let b: Vector<T, N> = m * mb * (alpha + alpha);
+        let d: T = c_ssr.d + c_ssr.c.dot(mb) * alpha;
+
+        Self { a, b, c: c_ssr.c, d, ts }
+    }
+
+    pub fn poles(&self) -> Option<[Complex<T>; N]> {
+        self.a.eigenvalues()
+    }
+
+    /* all poles lie inside the unit circle */
+    pub fn is_stable(&self) -> Option<bool> {
+        let poles: [Complex<T>; N] = self.poles()?;
+        Some(poles.iter().all(|p| p.norm() < T::one()))
+    }
+}
 
-        let a: Matrix<T, N, N> = (c_ssr.a * ts).exp();
-        let b: Vector<T, N> = a_int * c_ssr.b;
-        let c: Vector<T, N> = c_ssr.c;
-        Self { a, b, c, ts }
+/* exp(A * ts), int_0^ts exp(A * t) dt * B and int_0^ts (ts - t) * exp(A * t) dt * B by the Taylor series on h = ts / 2^s */
+/* and s doublings: G1(2h) = (I + exp(A * h)) * G1(h), G2(2h) = (I + exp(A * h)) * G2(h) + h * G1(h) */
+pub(super) fn hold_integrals<T, const N: usize, const M: usize>(
+    a: &Matrix<T, N, N>,
+    b: &Matrix<T, N, M>,
+    ts: T,
+) -> (Matrix<T, N, N>, Matrix<T, N, M>, Matrix<T, N, M>)
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    const SERIES_ORDER: usize = 16;
+    const MAX_SQUARING: usize = 64;
+    let t_05: T = T::from(0.5).unwrap();
+
+    let mut h: T = ts;
+    let mut squaring: usize = 0;
+    while (*a * h).infinity_norm() > t_05 && squaring < MAX_SQUARING {
+        h *= t_05;
+        squaring += 1;
+    }
+
+    //G1(h) = sum (A * h)^k * B * h / (k + 1)!, G2(h) = sum (A * h)^k * B * h^2 / (k + 2)!
+    let ah: Matrix<T, N, N> = *a * h;
+    let mut v: Matrix<T, N, M> = *b;
+    let mut c1: T = h;
+    let mut c2: T = h * h * t_05;
+    let mut g1: Matrix<T, N, M> = Matrix::new();
+    let mut g2: Matrix<T, N, M> = Matrix::new();
+    for k in 0..SERIES_ORDER {
+        g1 += v * c1;
+        g2 += v * c2;
+        v = ah * v;
+        c1 = c1 / T::from(k + 2).unwrap();
+        c2 = c2 / T::from(k + 3).unwrap();
     }
+
+    let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+    let mut ad: Matrix<T, N, N> = ah.exp();
+    for _ in 0..squaring {
+        let m: Matrix<T, N, N> = identity + ad;
+        g2 = m * g2 + g1 * h;
+        g1 = m * g1;
+        ad = ad * ad;
+        h = h + h;
+    }
+
+    (ad, g1, g2)
 }
 
 pub struct Plant<T, const N: usize> {
@@ -65,8 +158,9 @@ impl<T: Float + Default, const N: usize> Plant<T, N> {
         }
     }
 
+    /* y[k] = C * x[k] + D * u[k], then x[k + 1] = A * x[k] + B * u[k] */
     pub fn update(&mut self, u: T) {
+        self.y = self.ssr.c.dot(self.x) + self.ssr.d * u;
         self.x = self.ssr.a * self.x + self.ssr.b * u;
-        self.y = self.ssr.c.dot(self.x);
     }
 }
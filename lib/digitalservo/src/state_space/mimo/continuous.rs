@@ -0,0 +1,129 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use crate::state_space::continuous as siso;
+use num_complex::Complex;
+use num_traits::Float;
+
+/* N: states, M: inputs, P: outputs */
+#[derive(Debug, Copy, Clone)]
+pub struct SSR<T, const N: usize, const M: usize, const P: usize> {
+    pub a: Matrix<T, N, N>,
+    pub b: Matrix<T, N, M>,
+    pub c: Matrix<T, P, N>,
+    pub d: Matrix<T, P, M>,
+}
+
+impl<T: Float + Default, const N: usize, const M: usize, const P: usize> SSR<T, N, M, P> {
+    pub fn new(a: &[[T; N]; N], b: &[[T; M]; N], c: &[[T; N]; P]) -> Self {
+        Self {
+            a: Matrix::from(a),
+            b: Matrix::from(b),
+            c: Matrix::from(c),
+            d: Matrix::new(),
+        }
+    }
+
+    pub(super) fn zeros() -> Self {
+        Self {
+            a: Matrix::new(),
+            b: Matrix::new(),
+            c: Matrix::new(),
+            d: Matrix::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_d(mut self, d: &[[T; M]; P]) -> Self {
+        self.d = Matrix::from(d);
+        self
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    SSR<T, N, M, P>
+{
+    pub fn poles(&self) -> Option<[Complex<T>; N]> {
+        self.a.eigenvalues()
+    }
+
+    /* all poles lie in the open left half plane */
+    pub fn is_stable(&self) -> Option<bool> {
+        let poles: [Complex<T>; N] = self.poles()?;
+        Some(poles.iter().all(|p| p.re < T::zero()))
+    }
+
+    /* other after self */
+    pub fn series<const N2: usize, const P2: usize>(&self, other: &SSR<T, N2, P, P2>) -> SSR<T, { N + N2 }, M, P2>
+    where
+        [(); N + N2]:,
+    {
+        super::series(self, other)
+    }
+
+    /* self + other */
+    pub fn parallel<const N2: usize>(&self, other: &SSR<T, N2, M, P>) -> SSR<T, { N + N2 }, M, P>
+    where
+        [(); N + N2]:,
+    {
+        super::parallel(self, other)
+    }
+
+    /* other in the negative feedback path; returns None if the loop is not well-posed */
+    pub fn feedback<const N2: usize>(&self, other: &SSR<T, N2, P, M>) -> Option<SSR<T, { N + N2 }, M, P>>
+    where
+        [(); N + N2]:,
+    {
+        super::feedback(self, other)
+    }
+}
+
+impl<T: Float + Default, const N: usize> From<siso::SSR<T, N>> for SSR<T, N, 1, 1> {
+    fn from(ssr: siso::SSR<T, N>) -> Self {
+        let mut ret: SSR<T, N, 1, 1> = SSR::zeros();
+        ret.a = ssr.a;
+        for i in 0..N {
+            ret.b[i][0] = ssr.b[i];
+            ret.c[0][i] = ssr.c[i];
+        }
+        ret.d[0][0] = ssr.d;
+        ret
+    }
+}
+
+impl<T: Float + Default, const N: usize> SSR<T, N, 1, 1> {
+    pub fn to_siso(&self) -> siso::SSR<T, N> {
+        let mut b: Vector<T, N> = Vector::new();
+        let mut c: Vector<T, N> = Vector::new();
+        for i in 0..N {
+            b[i] = self.b[i][0];
+            c[i] = self.c[0][i];
+        }
+        siso::SSR { a: self.a, b, c, d: self.d[0][0] }
+    }
+}
+
+pub struct Plant<T, const N: usize, const M: usize, const P: usize> {
+    ssr: SSR<T, N, M, P>,
+    x: Vector<T, N>,
+    pub y: [T; P],
+    ts: T,
+}
+
+impl<T: Float + Default + AddAssign, const N: usize, const M: usize, const P: usize> Plant<T, N, M, P> {
+    pub fn new(ssr: &SSR<T, N, M, P>, ts: T) -> Self {
+        Self {
+            ssr: *ssr,
+            x: Vector::new(),
+            y: [T::zero(); P],
+            ts,
+        }
+    }
+
+    pub fn update(&mut self, u: &[T; M]) {
+        let u: Vector<T, M> = Vector::from(u);
+        let dx: Vector<T, N> = self.ssr.a * self.x + self.ssr.b * u;
+        self.x += dx * self.ts;
+        self.y = (self.ssr.c * self.x + self.ssr.d * u).data;
+    }
+}
@@ -0,0 +1,189 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use crate::state_space::discrete as siso;
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::continuous;
+
+/* N: states, M: inputs, P: outputs */
+#[derive(Debug, Copy, Clone)]
+pub struct SSR<T, const N: usize, const M: usize, const P: usize> {
+    pub a: Matrix<T, N, N>,
+    pub b: Matrix<T, N, M>,
+    pub c: Matrix<T, P, N>,
+    pub d: Matrix<T, P, M>,
+    pub ts: T,
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize, const M: usize, const P: usize>
+    SSR<T, N, M, P>
+{
+    pub fn new(ts: T) -> Self {
+        Self {
+            a: Matrix::new(),
+            b: Matrix::new(),
+            c: Matrix::new(),
+            d: Matrix::new(),
+            ts,
+        }
+    }
+
+    pub fn from_discrete_ssr(a: &[[T; N]; N], b: &[[T; M]; N], c: &[[T; N]; P], ts: T) -> Self {
+        Self {
+            a: Matrix::from(a),
+            b: Matrix::from(b),
+            c: Matrix::from(c),
+            d: Matrix::new(),
+            ts,
+        }
+    }
+
+    #[must_use]
+    pub fn set_d(mut self, d: &[[T; M]; P]) -> Self {
+        self.d = Matrix::from(d);
+        self
+    }
+
+    /* zero-order hold: Ad = exp(A * ts), Bd = int_0^ts exp(A * t) dt * B */
+    pub fn from_continuous_ssr(c_ssr: &continuous::SSR<T, N, M, P>, ts: T) -> Self {
+        let (a, b, _) = siso::hold_integrals(&c_ssr.a, &c_ssr.b, ts);
+        Self { a, b, c: c_ssr.c, d: c_ssr.d, ts }
+    }
+
+    /* first-order (triangle) hold: G1 = int_0^ts exp(A * t) dt * B, G2 = int_0^ts (ts - t) * exp(A * t) dt * B / ts */
+    pub fn from_continuous_ssr_foh(c_ssr: &continuous::SSR<T, N, M, P>, ts: T) -> Self {
+        let (a, g1, g2) = siso::hold_integrals(&c_ssr.a, &c_ssr.b, ts);
+        let g2: Matrix<T, N, M> = g2 * (T::one() / ts);
+
+        //the state is shifted by G2 * u so that the realization becomes causal
+        let b: Matrix<T, N, M> = g1 + a * g2 - g2;
+        let d: Matrix<T, P, M> = c_ssr.d + c_ssr.c * g2;
+
+        Self { a, b, c: c_ssr.c, d, ts }
+    }
+
+    /* bilinear transform s = (z - 1) / (alpha * (z + 1)); alpha = tan(w * ts / 2) / w if prewarped at w [rad/s] */
+    pub fn from_continuous_ssr_tustin(c_ssr: &continuous::SSR<T, N, M, P>, ts: T, prewarp: Option<T>) -> Self {
+        let t_05: T = T::from(0.5).unwrap();
+        let alpha: T = match prewarp {
+            Some(omega) if omega > T::zero() => (omega * ts * t_05).tan() / omega,
+            _ => ts * t_05,
+        };
+
+        let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+        let m: Matrix<T, N, N> = (identity - c_ssr.a * alpha).inverse().unwrap();
+        let mb: Matrix<T, N, M> = m * c_ssr.b;
+
+        let a: Matrix<T, N, N> = m * (identity + c_ssr.a * alpha);
+        let b: Matrix<T, N, M> = m * mb * (alpha + alpha);
+        let d: Matrix<T, P, M> = c_ssr.d + c_ssr.c * mb * alpha;
+
+        Self { a, b, c: c_ssr.c, d, ts }
+    }
+
+    pub fn poles(&self) -> Option<[Complex<T>; N]> {
+        self.a.eigenvalues()
+    }
+
+    /* all poles lie inside the unit circle */
+    pub fn is_stable(&self) -> Option<bool> {
+        let poles: [Complex<T>; N] = self.poles()?;
+        Some(poles.iter().all(|p| p.norm() < T::one()))
+    }
+
+    fn realization(&self) -> continuous::SSR<T, N, M, P> {
+        continuous::SSR { a: self.a, b: self.b, c: self.c, d: self.d }
+    }
+
+    fn from_realization<const N2: usize, const M2: usize, const P2: usize>(
+        r: continuous::SSR<T, N2, M2, P2>,
+        ts: T,
+    ) -> SSR<T, N2, M2, P2> {
+        SSR { a: r.a, b: r.b, c: r.c, d: r.d, ts }
+    }
+
+    fn check_ts(&self, ts: T) {
+        if self.ts != ts {
+            panic!("state space error: sampling time mismatch.")
+        }
+    }
+
+    /* other after self */
+    pub fn series<const N2: usize, const P2: usize>(&self, other: &SSR<T, N2, P, P2>) -> SSR<T, { N + N2 }, M, P2>
+    where
+        [(); N + N2]:,
+    {
+        self.check_ts(other.ts);
+        Self::from_realization(super::series(&self.realization(), &other.realization()), self.ts)
+    }
+
+    /* self + other */
+    pub fn parallel<const N2: usize>(&self, other: &SSR<T, N2, M, P>) -> SSR<T, { N + N2 }, M, P>
+    where
+        [(); N + N2]:,
+    {
+        self.check_ts(other.ts);
+        Self::from_realization(super::parallel(&self.realization(), &other.realization()), self.ts)
+    }
+
+    /* other in the negative feedback path; returns None if the loop is not well-posed */
+    pub fn feedback<const N2: usize>(&self, other: &SSR<T, N2, P, M>) -> Option<SSR<T, { N + N2 }, M, P>>
+    where
+        [(); N + N2]:,
+    {
+        self.check_ts(other.ts);
+        let r: continuous::SSR<T, { N + N2 }, M, P> = super::feedback(&self.realization(), &other.realization())?;
+        Some(Self::from_realization(r, self.ts))
+    }
+}
+
+impl<T: Float + Default, const N: usize> From<siso::SSR<T, N>> for SSR<T, N, 1, 1> {
+    fn from(ssr: siso::SSR<T, N>) -> Self {
+        let mut b: Matrix<T, N, 1> = Matrix::new();
+        let mut c: Matrix<T, 1, N> = Matrix::new();
+        for i in 0..N {
+            b[i][0] = ssr.b[i];
+            c[0][i] = ssr.c[i];
+        }
+        let mut d: Matrix<T, 1, 1> = Matrix::new();
+        d[0][0] = ssr.d;
+        SSR { a: ssr.a, b, c, d, ts: ssr.ts }
+    }
+}
+
+impl<T: Float + Default, const N: usize> SSR<T, N, 1, 1> {
+    pub fn to_siso(&self) -> siso::SSR<T, N> {
+        let mut b: Vector<T, N> = Vector::new();
+        let mut c: Vector<T, N> = Vector::new();
+        for i in 0..N {
+            b[i] = self.b[i][0];
+            c[i] = self.c[0][i];
+        }
+        siso::SSR { a: self.a, b, c, d: self.d[0][0], ts: self.ts }
+    }
+}
+
+pub struct Plant<T, const N: usize, const M: usize, const P: usize> {
+    ssr: SSR<T, N, M, P>,
+    x: Vector<T, N>,
+    pub y: [T; P],
+}
+
+impl<T: Float + Default, const N: usize, const M: usize, const P: usize> Plant<T, N, M, P> {
+    pub fn new(ssr: &SSR<T, N, M, P>) -> Self {
+        Self {
+            ssr: *ssr,
+            x: Vector::new(),
+            y: [T::zero(); P],
+        }
+    }
+
+    /* same timing as siso::Plant::update() */
+    pub fn update(&mut self, u: &[T; M]) {
+        let u: Vector<T, M> = Vector::from(u);
+        self.y = (self.ssr.c * self.x + self.ssr.d * u).data;
+        self.x = self.ssr.a * self.x + self.ssr.b * u;
+    }
+}
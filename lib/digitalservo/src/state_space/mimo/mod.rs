@@ -0,0 +1,153 @@
+pub mod continuous;
+pub mod discrete;
+
+use crate::algebra::*;
+use num_traits::Float;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+/* the interconnections only depend on (A, B, C, D), so they are shared by the continuous and discrete systems */
+
+/* g2 after g1 */
+fn series<T, const N1: usize, const N2: usize, const M: usize, const P1: usize, const P2: usize>(
+    g1: &continuous::SSR<T, N1, M, P1>,
+    g2: &continuous::SSR<T, N2, P1, P2>,
+) -> continuous::SSR<T, { N1 + N2 }, M, P2>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N1 + N2]:,
+{
+    let b2c1: Matrix<T, N2, N1> = g2.b * g1.c;
+    let b2d1: Matrix<T, N2, M> = g2.b * g1.d;
+    let d2c1: Matrix<T, P2, N1> = g2.d * g1.c;
+
+    let mut ret: continuous::SSR<T, { N1 + N2 }, M, P2> = continuous::SSR::zeros();
+    for i in 0..N1 {
+        for j in 0..N1 {
+            ret.a[i][j] = g1.a[i][j];
+        }
+        for j in 0..M {
+            ret.b[i][j] = g1.b[i][j];
+        }
+    }
+    for i in 0..N2 {
+        for j in 0..N1 {
+            ret.a[N1 + i][j] = b2c1[i][j];
+        }
+        for j in 0..N2 {
+            ret.a[N1 + i][N1 + j] = g2.a[i][j];
+        }
+        for j in 0..M {
+            ret.b[N1 + i][j] = b2d1[i][j];
+        }
+    }
+    for i in 0..P2 {
+        for j in 0..N1 {
+            ret.c[i][j] = d2c1[i][j];
+        }
+        for j in 0..N2 {
+            ret.c[i][N1 + j] = g2.c[i][j];
+        }
+    }
+    ret.d = g2.d * g1.d;
+    ret
+}
+
+/* g1 + g2 */
+fn parallel<T, const N1: usize, const N2: usize, const M: usize, const P: usize>(
+    g1: &continuous::SSR<T, N1, M, P>,
+    g2: &continuous::SSR<T, N2, M, P>,
+) -> continuous::SSR<T, { N1 + N2 }, M, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N1 + N2]:,
+{
+    let mut ret: continuous::SSR<T, { N1 + N2 }, M, P> = continuous::SSR::zeros();
+    for i in 0..N1 {
+        for j in 0..N1 {
+            ret.a[i][j] = g1.a[i][j];
+        }
+        for j in 0..M {
+            ret.b[i][j] = g1.b[i][j];
+        }
+    }
+    for i in 0..N2 {
+        for j in 0..N2 {
+            ret.a[N1 + i][N1 + j] = g2.a[i][j];
+        }
+        for j in 0..M {
+            ret.b[N1 + i][j] = g2.b[i][j];
+        }
+    }
+    for i in 0..P {
+        for j in 0..N1 {
+            ret.c[i][j] = g1.c[i][j];
+        }
+        for j in 0..N2 {
+            ret.c[i][N1 + j] = g2.c[i][j];
+        }
+    }
+    ret.d = g1.d + g2.d;
+    ret
+}
+
+/* g1 with g2 in the negative feedback path: u1 = r - y2; returns None if I + D2 * D1 is singular */
+fn feedback<T, const N1: usize, const N2: usize, const M: usize, const P: usize>(
+    g1: &continuous::SSR<T, N1, M, P>,
+    g2: &continuous::SSR<T, N2, P, M>,
+) -> Option<continuous::SSR<T, { N1 + N2 }, M, P>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N1 + N2]:,
+{
+    //u1 = E * (r - D2 * C1 * x1 - C2 * x2), E = (I + D2 * D1)^-1
+    let identity: Matrix<T, M, M> = Matrix::diag(T::one());
+    let e: Matrix<T, M, M> = (identity + g2.d * g1.d).inverse()?;
+    let k1: Matrix<T, M, N1> = e * g2.d * g1.c;
+    let k2: Matrix<T, M, N2> = e * g2.c;
+
+    //y1 = C1 * x1 + D1 * u1
+    let c1: Matrix<T, P, N1> = g1.c - g1.d * k1;
+    let c2: Matrix<T, P, N2> = g1.d * k2 * -T::one();
+    let d: Matrix<T, P, M> = g1.d * e;
+
+    let a11: Matrix<T, N1, N1> = g1.a - g1.b * k1;
+    let a12: Matrix<T, N1, N2> = g1.b * k2 * -T::one();
+    let a21: Matrix<T, N2, N1> = g2.b * c1;
+    let a22: Matrix<T, N2, N2> = g2.a + g2.b * c2;
+    let b1: Matrix<T, N1, M> = g1.b * e;
+    let b2: Matrix<T, N2, M> = g2.b * d;
+
+    let mut ret: continuous::SSR<T, { N1 + N2 }, M, P> = continuous::SSR::zeros();
+    for i in 0..N1 {
+        for j in 0..N1 {
+            ret.a[i][j] = a11[i][j];
+        }
+        for j in 0..N2 {
+            ret.a[i][N1 + j] = a12[i][j];
+        }
+        for j in 0..M {
+            ret.b[i][j] = b1[i][j];
+        }
+    }
+    for i in 0..N2 {
+        for j in 0..N1 {
+            ret.a[N1 + i][j] = a21[i][j];
+        }
+        for j in 0..N2 {
+            ret.a[N1 + i][N1 + j] = a22[i][j];
+        }
+        for j in 0..M {
+            ret.b[N1 + i][j] = b2[i][j];
+        }
+    }
+    for i in 0..P {
+        for j in 0..N1 {
+            ret.c[i][j] = c1[i][j];
+        }
+        for j in 0..N2 {
+            ret.c[i][N1 + j] = c2[i][j];
+        }
+    }
+    ret.d = d;
+    Some(ret)
+}
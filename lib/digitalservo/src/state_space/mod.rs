@@ -1,3 +1,6 @@
 pub mod blocked;
 pub mod continuous;
+pub mod controllability;
 pub mod discrete;
+pub mod mimo;
+pub mod pole_placement;
@@ -0,0 +1,108 @@
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::algebra::*;
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::controllability::siso_controllability_matrix;
+use super::{continuous, discrete};
+
+/* real coefficients of (x - p_1) * ... * (x - p_N) except the leading 1; returns None if the poles are not closed under conjugation */
+fn desired_polynomial<T: Float, const N: usize>(poles: &[Complex<T>; N]) -> Option<[T; N]> {
+    let mut coefficients: [Complex<T>; N] = [Complex::new(T::zero(), T::zero()); N];
+    for (k, p) in poles.iter().enumerate() {
+        for j in (1..(k + 1)).rev() {
+            coefficients[j] = coefficients[j] - *p * coefficients[j - 1];
+        }
+        coefficients[0] = coefficients[0] - *p;
+    }
+
+    let scale: T = coefficients.iter().fold(T::one(), |acc, c| acc.max(c.norm()));
+    let tolerance: T = T::from(N + 1).unwrap() * T::epsilon().sqrt() * scale;
+
+    let mut ret: [T; N] = [T::zero(); N];
+    for i in 0..N {
+        if coefficients[i].im.abs() > tolerance {
+            return None;
+        }
+        ret[i] = coefficients[i].re;
+    }
+    Some(ret)
+}
+
+/* K = e_N^T * Uc^-1 * phi(A), where phi is the desired characteristic polynomial */
+pub fn ackermann<T, const N: usize>(a: &Matrix<T, N, N>, b: &Vector<T, N>, poles: &[Complex<T>; N]) -> Option<Vector<T, N>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let alpha: [T; N] = desired_polynomial(poles)?;
+
+    let uc: Matrix<T, N, N> = siso_controllability_matrix(a, b);
+    if uc.rank() < N {
+        return None;
+    }
+
+    //Horner's rule: phi(A) = (...((A + alpha_1 I) A + alpha_2 I) A ...) + alpha_N I
+    let identity: Matrix<T, N, N> = Matrix::diag(T::one());
+    let mut phi: Matrix<T, N, N> = identity;
+    for alpha_i in alpha.iter() {
+        phi = phi * *a + identity * *alpha_i;
+    }
+
+    let uc_inv: Matrix<T, N, N> = uc.inverse()?;
+    Some(Vector::from((uc_inv * phi)[N - 1]))
+}
+
+/* K = (alpha - a)^T * (Uc * W)^-1, where W is the Hankel matrix of the open-loop characteristic polynomial */
+pub fn bass_gura<T, const N: usize>(a: &Matrix<T, N, N>, b: &Vector<T, N>, poles: &[Complex<T>; N]) -> Option<Vector<T, N>>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    let alpha: [T; N] = desired_polynomial(poles)?;
+    let open_loop: [T; N] = a.characteristic_coefficients();
+
+    let uc: Matrix<T, N, N> = siso_controllability_matrix(a, b);
+    if uc.rank() < N {
+        return None;
+    }
+
+    let mut w: Matrix<T, N, N> = Matrix::new();
+    for i in 0..N {
+        for j in 0..(N - i) {
+            w[i][j] = if i + j == N - 1 { T::one() } else { open_loop[N - 2 - i - j] };
+        }
+    }
+
+    //the coefficients are ordered from the lowest power to match the columns of Uc * W
+    let mut diff: Vector<T, N> = Vector::new();
+    for i in 0..N {
+        diff[i] = alpha[N - 1 - i] - open_loop[N - 1 - i];
+    }
+
+    let m_inv: Matrix<T, N, N> = (uc * w).inverse()?;
+    Some(m_inv.transpose() * diff)
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> continuous::SSR<T, N> {
+    /* eig(A - B * K) = poles for u = -K * x; returns None if the system is not controllable */
+    pub fn state_feedback_gain(&self, poles: &[Complex<T>; N]) -> Option<Vector<T, N>> {
+        bass_gura(&self.a, &self.b, poles)
+    }
+
+    /* eig(A - L * C) = poles for dx_hat = A * x_hat + B * u + L * (y - C * x_hat); returns None if the system is not observable */
+    pub fn observer_gain(&self, poles: &[Complex<T>; N]) -> Option<Vector<T, N>> {
+        bass_gura(&self.a.transpose(), &self.c, poles)
+    }
+}
+
+impl<T: Float + Default + AddAssign + SubAssign + MulAssign, const N: usize> discrete::SSR<T, N> {
+    /* eig(A - B * K) = poles for u = -K * x; returns None if the system is not controllable */
+    pub fn state_feedback_gain(&self, poles: &[Complex<T>; N]) -> Option<Vector<T, N>> {
+        bass_gura(&self.a, &self.b, poles)
+    }
+
+    /* eig(A - L * C) = poles for x_hat[k + 1] = A * x_hat[k] + B * u[k] + L * (y[k] - C * x_hat[k]); returns None if the system is not observable */
+    pub fn observer_gain(&self, poles: &[Complex<T>; N]) -> Option<Vector<T, N>> {
+        bass_gura(&self.a.transpose(), &self.c, poles)
+    }
+}
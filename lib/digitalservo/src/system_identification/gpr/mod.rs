@@ -1,5 +1,6 @@
 use std::ops::{AddAssign, MulAssign};
 
+use crate::algebra::eigen::cholesky_decomposition::{backward_substitution_transposed, cholesky_factor, forward_substitution};
 use num_traits::Float;
 
 pub struct GaussianProcessRegression<T> {
@@ -9,7 +10,7 @@ pub struct GaussianProcessRegression<T> {
     pub x_min: T,
     kernel: fn(T, T) -> T,
     sense_variance: T,
-    inv_cov: Vec<Vec<T>>,
+    cov_cholesky: Vec<Vec<T>>,
     sample: usize,
 }
 
@@ -28,7 +29,7 @@ impl<T: Float + AddAssign + MulAssign> GaussianProcessRegression<T> {
             x_min: T::zero(),
             kernel,
             sense_variance: sigma,
-            inv_cov: vec![vec![]],
+            cov_cholesky: vec![],
             sample: 0,
         }
     }
@@ -52,15 +53,18 @@ impl<T: Float + AddAssign + MulAssign> GaussianProcessRegression<T> {
         self.sample += 1;
     }
 
-    pub fn predict(&mut self, x: T) -> PredictedValue<T> {
-        if self.inv_cov.len() != self.sample {
+    /* returns None if the kernel matrix of the samples is not positive definite */
+    pub fn predict(&mut self, x: T) -> Option<PredictedValue<T>> {
+        if self.cov_cholesky.len() != self.sample {
             let mut buffer: Vec<Vec<T>> = vec![vec![T::zero(); self.sample]; self.sample];
             for i in 0..self.sample {
                 for j in 0..self.sample {
                     buffer[i][j] = (self.kernel)(self.x_sample[i], self.x_sample[j]);
                 }
             }
-            self.inv_cov = inverse(&buffer).unwrap();
+            let mut l: Vec<Vec<T>> = vec![vec![T::zero(); self.sample]; self.sample];
+            cholesky_factor(&buffer, &mut l)?;
+            self.cov_cholesky = l;
         }
 
         let mut k: Vec<T> = vec![T::zero(); self.sample];
@@ -68,83 +72,28 @@ impl<T: Float + AddAssign + MulAssign> GaussianProcessRegression<T> {
             k[i] = (self.kernel)(self.x_sample[i], x);
         }
 
-        let mut buffer1: Vec<T> = vec![T::zero(); self.sample];
-        let mut buffer2: T = T::zero();
-
+        //mean = k^T * K^-1 * y
+        let mut y: Vec<T> = vec![T::zero(); self.sample];
+        let mut alpha: Vec<T> = vec![T::zero(); self.sample];
+        forward_substitution(&self.cov_cholesky, &self.y_sample, &mut y);
+        backward_substitution_transposed(&self.cov_cholesky, &y, &mut alpha);
         let mut mean: T = T::zero();
         for i in 0..self.sample {
-            for j in 0..self.sample {
-                buffer1[i] += self.inv_cov[i][j] * self.y_sample[j];
-            }
-            mean += k[i] * buffer1[i];
+            mean += k[i] * alpha[i];
         }
 
+        //k^T * K^-1 * k = |L^-1 * k|^2
+        let mut v: Vec<T> = vec![T::zero(); self.sample];
+        forward_substitution(&self.cov_cholesky, &k, &mut v);
+        let mut buffer2: T = T::zero();
         for i in 0..self.sample {
-            buffer1[i] = T::zero();
-        }
-
-        for i in 0..self.sample {
-            for j in 0..self.sample {
-                buffer1[i] += self.inv_cov[i][j] * k[j];
-            }
-            buffer2 += k[i] * buffer1[i];
+            buffer2 += v[i] * v[i];
         }
 
         let stdev: T = ((self.kernel)(x, x) - buffer2 + self.sense_variance)
             .abs()
             .sqrt();
 
-        PredictedValue { mean, stdev }
-    }
-}
-
-fn inverse<T: Float + MulAssign>(m: &Vec<Vec<T>>) -> Option<Vec<Vec<T>>> {
-    let vlen: usize = m.len();
-
-    let mut m1: Vec<Vec<T>> = m.clone();
-    let mut m2: Vec<Vec<T>> = vec![vec![T::zero(); vlen]; vlen];
-    for i in 0..vlen {
-        m2[i][i] = T::one();
-    }
-
-    for i in 0..vlen {
-        let mut max_row_option: usize = 0;
-        let mut max_value_option: T = T::zero();
-        for j in i..vlen {
-            if m1[j][i].abs() > max_value_option {
-                max_value_option = m1[j][i].abs();
-                max_row_option = j;
-            }
-        }
-
-        /*Error Handling */
-        if max_value_option == T::zero() {
-            return None;
-        }
-
-        let m1_buffer: Vec<T> = m1[i].clone();
-        let m2_buffer: Vec<T> = m2[i].clone();
-        m1[i] = m1[max_row_option].clone();
-        m2[i] = m2[max_row_option].clone();
-        m1[max_row_option] = m1_buffer;
-        m2[max_row_option] = m2_buffer;
-
-        let scaler: T = T::one() / m1[i][i];
-        for j in 0..vlen {
-            m1[i][j] *= scaler;
-            m2[i][j] *= scaler;
-        }
-
-        for j in 0..vlen {
-            if i != j {
-                let scaler: T = m1[j][i];
-                for k in 0..vlen {
-                    m1[j][k] = m1[j][k] - scaler * m1[i][k];
-                    m2[j][k] = m2[j][k] - scaler * m2[i][k];
-                }
-            }
-        }
+        Some(PredictedValue { mean, stdev })
     }
-
-    Some(m2)
 }
@@ -55,9 +55,9 @@ where
     }
 
     pub fn identify(&mut self) -> Option<[T; P + Z + 1]> {
-        match self.phi_sum.inverse() {
+        match Eigen::cholesky_decomposition(self.phi_sum) {
             Some(res) => {
-                let theta = res * self.psi_sum;
+                let theta = res.solve(self.psi_sum);
                 Some(theta.data)
             }
             None => None,
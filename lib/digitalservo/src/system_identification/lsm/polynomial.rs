@@ -33,9 +33,9 @@ where
     }
 
     pub fn identify(&mut self) -> Option<[T; N + 1]> {
-        match self.phi_sum.inverse() {
+        match Eigen::cholesky_decomposition(self.phi_sum) {
             Some(res) => {
-                let theta = res * self.psi_sum;
+                let theta = res.solve(self.psi_sum);
                 Some(theta.data)
             }
             None => None,
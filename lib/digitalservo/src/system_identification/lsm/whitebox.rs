@@ -24,9 +24,9 @@ impl<T: Float + Default + AddAssign + MulAssign, const P: usize> DataBuffer<T, P
     }
 
     pub fn identify(&mut self) -> Option<[T; P]> {
-        match self.phi_sum.inverse() {
+        match Eigen::cholesky_decomposition(self.phi_sum) {
             Some(res) => {
-                let theta: Vector<T, P> = res * self.psi_sum;
+                let theta: Vector<T, P> = res.solve(self.psi_sum);
                 Some(theta.data)
             }
             None => None,
@@ -0,0 +1,112 @@
+use super::*;
+use crate::state_space::{continuous, discrete};
+
+impl<T, const Z: usize, const P: usize> TransferFunction<T, Z, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); P - 1]:,
+{
+    /* returns None if the system is improper or the leading denominator coefficient is zero */
+    fn canonical_realization(&self, form: CanonicalForm) -> Option<continuous::SSR<T, { P - 1 }>> {
+        if self.denom[0] == T::zero() || polynomial_degree(&self.numer) > P - 1 {
+            return None;
+        }
+
+        //numerator aligned to the denominator, both normalized by the leading denominator coefficient
+        let mut numer: [T; P] = [T::zero(); P];
+        for i in 0..std::cmp::min(Z, P) {
+            numer[P - 1 - i] = self.numer[Z - 1 - i] / self.denom[0];
+        }
+        let denom: [T; P] = self.denom.map(|x| x / self.denom[0]);
+
+        //feedthrough and the strictly proper remainder
+        let d: T = numer[0];
+        let mut a: Matrix<T, { P - 1 }, { P - 1 }> = Matrix::new();
+        let mut b: Vector<T, { P - 1 }> = Vector::new();
+        let mut c: Vector<T, { P - 1 }> = Vector::new();
+
+        for i in 0..(P - 1) {
+            if i + 1 < P - 1 {
+                a[i][i + 1] = T::one();
+            }
+            a[P - 2][P - 2 - i] = -denom[i + 1];
+            c[P - 2 - i] = numer[i + 1] - d * denom[i + 1];
+        }
+        if P > 1 {
+            b[P - 2] = T::one();
+        }
+
+        match form {
+            CanonicalForm::Controllable => Some(continuous::SSR { a, b, c, d }),
+            CanonicalForm::Observable => Some(continuous::SSR { a: a.transpose(), b: c, c: b, d }),
+        }
+    }
+
+    /* returns None if the transfer function is discrete or has no realization of order P - 1 */
+    pub fn to_continuous_ssr(&self, form: CanonicalForm) -> Option<continuous::SSR<T, { P - 1 }>> {
+        if self.is_discrete() {
+            return None;
+        }
+        self.canonical_realization(form)
+    }
+
+    /* returns None if the transfer function is continuous or has no realization of order P - 1 */
+    pub fn to_discrete_ssr(&self, form: CanonicalForm) -> Option<discrete::SSR<T, { P - 1 }>> {
+        let ts: T = self.ts?;
+        let ssr: continuous::SSR<T, { P - 1 }> = self.canonical_realization(form)?;
+        Some(discrete::SSR { a: ssr.a, b: ssr.b, c: ssr.c, d: ssr.d, ts })
+    }
+}
+
+/* C * adj(sI - A) * B = det(sI - A + B * C) - det(sI - A) (matrix determinant lemma) */
+fn ssr_polynomials<T, const N: usize>(
+    a: &Matrix<T, N, N>,
+    b: &Vector<T, N>,
+    c: &Vector<T, N>,
+    d: T,
+) -> ([T; N + 1], [T; N + 1])
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+    [(); N + 1]:,
+{
+    let mut abc: Matrix<T, N, N> = *a;
+    for i in 0..N {
+        for j in 0..N {
+            abc[i][j] -= b[i] * c[j];
+        }
+    }
+
+    let denom: [T; N + 1] = a.characteristic_polynomial();
+    let closed: [T; N + 1] = abc.characteristic_polynomial();
+    let mut numer: [T; N + 1] = [T::zero(); N + 1];
+    for i in 0..(N + 1) {
+        numer[i] = closed[i] - denom[i] + d * denom[i];
+    }
+    (numer, denom)
+}
+
+impl<T, const N: usize> continuous::SSR<T, N>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    pub fn to_transfer_function(&self) -> TransferFunction<T, { N + 1 }, { N + 1 }>
+    where
+        [(); N + 1]:,
+    {
+        let (numer, denom) = ssr_polynomials(&self.a, &self.b, &self.c, self.d);
+        TransferFunction { numer, denom, ts: None }
+    }
+}
+
+impl<T, const N: usize> discrete::SSR<T, N>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    pub fn to_transfer_function(&self) -> TransferFunction<T, { N + 1 }, { N + 1 }>
+    where
+        [(); N + 1]:,
+    {
+        let (numer, denom) = ssr_polynomials(&self.a, &self.b, &self.c, self.d);
+        TransferFunction { numer, denom, ts: Some(self.ts) }
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+impl<T, const Z: usize, const P: usize> TransferFunction<T, Z, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    fn common_ts<const Z2: usize, const P2: usize>(&self, other: &TransferFunction<T, Z2, P2>) -> Option<T> {
+        match (self.ts, other.ts) {
+            (None, None) => None,
+            (Some(ts1), Some(ts2)) if ts1 == ts2 => Some(ts1),
+            _ => panic!("transfer function error: sampling time mismatch."),
+        }
+    }
+
+    /* other * self */
+    pub fn series<const Z2: usize, const P2: usize>(
+        &self,
+        other: &TransferFunction<T, Z2, P2>,
+    ) -> TransferFunction<T, { Z + Z2 - 1 }, { P + P2 - 1 }>
+    where
+        [(); Z + Z2 - 1]:,
+        [(); P + P2 - 1]:,
+    {
+        TransferFunction {
+            numer: polynomial_multiply(&self.numer, &other.numer),
+            denom: polynomial_multiply(&self.denom, &other.denom),
+            ts: self.common_ts(other),
+        }
+    }
+
+    /* self + other; both must be proper */
+    pub fn parallel<const Z2: usize, const P2: usize>(
+        &self,
+        other: &TransferFunction<T, Z2, P2>,
+    ) -> TransferFunction<T, { P + P2 - 1 }, { P + P2 - 1 }>
+    where
+        [(); Z + P2 - 1]:,
+        [(); Z2 + P - 1]:,
+        [(); P + P2 - 1]:,
+    {
+        if Z > P || Z2 > P2 {
+            panic!("transfer function error: improper system.")
+        }
+
+        let n1d2: [T; Z + P2 - 1] = polynomial_multiply(&self.numer, &other.denom);
+        let n2d1: [T; Z2 + P - 1] = polynomial_multiply(&other.numer, &self.denom);
+
+        TransferFunction {
+            numer: polynomial_add(&n1d2, &n2d1),
+            denom: polynomial_multiply(&self.denom, &other.denom),
+            ts: self.common_ts(other),
+        }
+    }
+
+    /* self / (1 + self * other), i.e. other in the negative feedback path; both must be proper */
+    pub fn feedback<const Z2: usize, const P2: usize>(
+        &self,
+        other: &TransferFunction<T, Z2, P2>,
+    ) -> TransferFunction<T, { Z + P2 - 1 }, { P + P2 - 1 }>
+    where
+        [(); Z + P2 - 1]:,
+        [(); Z + Z2 - 1]:,
+        [(); P + P2 - 1]:,
+    {
+        if Z > P || Z2 > P2 {
+            panic!("transfer function error: improper system.")
+        }
+
+        let d1d2: [T; P + P2 - 1] = polynomial_multiply(&self.denom, &other.denom);
+        let n1n2: [T; Z + Z2 - 1] = polynomial_multiply(&self.numer, &other.numer);
+
+        TransferFunction {
+            numer: polynomial_multiply(&self.numer, &other.denom),
+            denom: polynomial_add(&d1d2, &n1n2),
+            ts: self.common_ts(other),
+        }
+    }
+
+    /* self / (1 + self) */
+    pub fn unity_feedback(&self) -> TransferFunction<T, Z, P> {
+        if Z > P {
+            panic!("transfer function error: improper system.")
+        }
+
+        TransferFunction {
+            numer: self.numer,
+            denom: polynomial_add(&self.denom, &self.numer),
+            ts: self.ts,
+        }
+    }
+}
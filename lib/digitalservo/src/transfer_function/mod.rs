@@ -0,0 +1,184 @@
+mod conversion;
+mod interconnection;
+
+use crate::algebra::*;
+use num_complex::Complex;
+use num_traits::Float;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CanonicalForm {
+    Controllable,
+    Observable,
+}
+
+/* coefficients are stored in descending powers of s (continuous) or z (discrete) */
+#[derive(Debug, Clone, Copy)]
+pub struct TransferFunction<T, const Z: usize, const P: usize> {
+    pub numer: [T; Z],
+    pub denom: [T; P],
+    pub ts: Option<T>,
+}
+
+impl<T, const Z: usize, const P: usize> TransferFunction<T, Z, P>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    pub fn new(numer: &[T; Z], denom: &[T; P]) -> Self {
+        Self {
+            numer: *numer,
+            denom: *denom,
+            ts: None,
+        }
+    }
+
+    pub fn new_discrete(numer: &[T; Z], denom: &[T; P], ts: T) -> Self {
+        Self {
+            numer: *numer,
+            denom: *denom,
+            ts: Some(ts),
+        }
+    }
+
+    pub fn is_discrete(&self) -> bool {
+        self.ts.is_some()
+    }
+
+    pub fn is_proper(&self) -> bool {
+        polynomial_degree(&self.numer) <= polynomial_degree(&self.denom)
+    }
+
+    /* value at the complex point s (continuous) or z (discrete) */
+    pub fn eval(&self, x: Complex<T>) -> Complex<T> {
+        polynomial_eval(&self.numer, x) / polynomial_eval(&self.denom, x)
+    }
+
+    /* value at s = j * omega or z = exp(j * omega * ts) */
+    pub fn frequency_response(&self, omega: T) -> Complex<T> {
+        let x: Complex<T> = match self.ts {
+            Some(ts) => Complex::new(T::zero(), omega * ts).exp(),
+            None => Complex::new(T::zero(), omega),
+        };
+        self.eval(x)
+    }
+
+    pub fn dc_gain(&self) -> T {
+        let x: Complex<T> = match self.ts {
+            Some(_) => Complex::new(T::one(), T::zero()),
+            None => Complex::new(T::zero(), T::zero()),
+        };
+        self.eval(x).re
+    }
+
+    /* roots of the denominator; the missing ones of a degenerate polynomial are placed at infinity */
+    pub fn poles(&self) -> Option<[Complex<T>; P - 1]>
+    where
+        [(); P - 1]:,
+    {
+        polynomial_roots(&self.denom)
+    }
+
+    /* roots of the numerator; the missing ones of a degenerate polynomial are placed at infinity */
+    pub fn zeros(&self) -> Option<[Complex<T>; Z - 1]>
+    where
+        [(); Z - 1]:,
+    {
+        polynomial_roots(&self.numer)
+    }
+
+    /* gain * (x - z_1) * ... * (x - z_m) / ((x - p_1) * ... * (x - p_n)) */
+    pub fn from_zeros_poles_gain(
+        zeros: &[Complex<T>; Z - 1],
+        poles: &[Complex<T>; P - 1],
+        gain: T,
+        ts: Option<T>,
+    ) -> Self
+    where
+        [(); Z - 1]:,
+        [(); P - 1]:,
+        [(); Z - 1 + 1]:,
+        [(); P - 1 + 1]:,
+    {
+        let numer_c: [Complex<T>; Z - 1 + 1] = vieta_formula::vieta_formula(zeros);
+        let denom_c: [Complex<T>; P - 1 + 1] = vieta_formula::vieta_formula(poles);
+
+        let mut numer: [T; Z] = [T::zero(); Z];
+        let mut denom: [T; P] = [T::zero(); P];
+        for i in 0..Z {
+            numer[i] = numer_c[i].re * gain;
+        }
+        for i in 0..P {
+            denom[i] = denom_c[i].re;
+        }
+
+        Self { numer, denom, ts }
+    }
+}
+
+fn polynomial_degree<T: Float>(c: &[T]) -> usize {
+    match c.iter().position(|&x| x != T::zero()) {
+        Some(i) => c.len() - 1 - i,
+        None => 0,
+    }
+}
+
+fn polynomial_eval<T: Float>(c: &[T], x: Complex<T>) -> Complex<T> {
+    c.iter()
+        .fold(Complex::new(T::zero(), T::zero()), |acc, &ci| acc * x + ci)
+}
+
+fn polynomial_roots<T: Float + Default, const N: usize>(c: &[T; N]) -> Option<[Complex<T>; N - 1]>
+where
+    [(); N - 1]:,
+{
+    let mut roots: [Complex<T>; N - 1] = [Complex::new(T::infinity(), T::zero()); N - 1];
+    let leading: usize = c.iter().position(|&x| x != T::zero())?;
+    if leading == N - 1 {
+        return Some(roots);
+    }
+
+    //shift out the leading zeros: p(x) * x^leading has the same finite roots plus extra ones at the origin
+    //dka_method expects a monic polynomial
+    let mut shifted: [T; N] = [T::zero(); N];
+    for i in 0..(N - leading) {
+        shifted[i] = c[leading + i] / c[leading];
+    }
+    let mut found: [Complex<T>; N - 1] = dka_method::dka_method(shifted)?;
+
+    found.sort_by(|a, b| b.norm().partial_cmp(&a.norm()).unwrap_or(std::cmp::Ordering::Equal));
+    roots[..(N - 1 - leading)].copy_from_slice(&found[..(N - 1 - leading)]);
+    Some(roots)
+}
+
+fn polynomial_multiply<T, const A: usize, const B: usize>(x: &[T; A], y: &[T; B]) -> [T; A + B - 1]
+where
+    T: Float + AddAssign,
+    [(); A + B - 1]:,
+{
+    let mut ret: [T; A + B - 1] = [T::zero(); A + B - 1];
+    for i in 0..A {
+        for j in 0..B {
+            ret[i + j] += x[i] * y[j];
+        }
+    }
+    ret
+}
+
+/* x + y, aligned at the lowest order and written into N coefficients */
+fn polynomial_add<T, const A: usize, const B: usize, const N: usize>(x: &[T; A], y: &[T; B]) -> [T; N]
+where
+    T: Float + AddAssign,
+{
+    let mut ret: [T; N] = [T::zero(); N];
+    for i in 0..A {
+        if x[A - 1 - i] != T::zero() {
+            ret[N - 1 - i] += x[A - 1 - i];
+        }
+    }
+    for i in 0..B {
+        if y[B - 1 - i] != T::zero() {
+            ret[N - 1 - i] += y[B - 1 - i];
+        }
+    }
+    ret
+}
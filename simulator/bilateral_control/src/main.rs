@@ -7,6 +7,7 @@ use std::error::Error;
 use digitalservo::data_storage::DataStorage;
 use digitalservo::mclib::controller;
 use digitalservo::observer::disturbance_observer as dob;
+use digitalservo::observer::reaction_force_observer as rfob;
 use digitalservo::plant::motor as plant;
 use digitalservo::signal::integrator;
 
@@ -45,7 +46,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let g: f64 = 300.0;
     let mut dob = [dob::VelocityBased::<_, 2>::new(TS, kt, jm, g); 2];
-    let mut rfob = [dob::VelocityBased::<_, 2>::new(TS, kt, jm, g); 2];
+    let mut rfob = [rfob::ReactionForceObserver::<_, 2>::new(TS, kt, jm, g); 2];
 
     //Logging
     const DATAILE_SEPARATOR: &str = ",";
@@ -75,8 +76,8 @@ fn main() -> Result<(), Box<dyn Error>> {
             dob[1].update(iq_ref[1], plant[1].d1x),
         ];
         let tau_est: [f64; 2] = [
-            rfob[0].update(iq_ref[0], plant[0].d1x),
-            rfob[1].update(iq_ref[1], plant[1].d1x),
+            rfob[0].update(iq_ref[0], plant[0].d1x, plant[0].d0x),
+            rfob[1].update(iq_ref[1], plant[1].d1x, plant[1].d0x),
         ];
 
         let i_cmp: [f64; 2] = [tau_dis[0] / kt, tau_dis[1] / kt];
@@ -1,11 +1,15 @@
 use std::error::Error;
 
-use digitalservo::state_space::{continuous, blocked};
+use digitalservo::mclib::perfect_tracking::PerfectTrackingController;
+use digitalservo::state_space::continuous;
 use digitalservo::signal::stable_inversion::StableInverter;
 use digitalservo::data_storage::DataStorage;
 
 const PLANT_ORDER: usize = 2;
 
+//Deadbeat state feedback on top of the multirate feedforward
+const STATE_FEEDBACK: bool = false;
+
 fn main() -> Result<(), Box<dyn Error>>{
 
     const SAMPLES: usize = 2000;
@@ -26,7 +30,6 @@ fn main() -> Result<(), Box<dyn Error>>{
     let b_c: [f64; 2] = [0.0, 1.0];
     let c_c: [f64; 2] = [1.0, -1.0 / 50.0];
     let c_ssr = continuous::SSR::new(&a_c, &b_c, &c_c);
-    let mut b_ssr = blocked::BlockedSSR::from_continuous_ssr(&c_ssr, TS);
 
     //Reference
     fn reference(t: f64) -> [f64; 2] {
@@ -44,31 +47,26 @@ fn main() -> Result<(), Box<dyn Error>>{
     fn f_unstable(t: f64) -> f64 { 50.0 * (- 50.0 * t).exp() }
     let mut stable_inverter = StableInverter::new(reference, None, Some(f_unstable), T_MAX);
 
-    //Calcularate feedforward signal
-    let mut ref_z1: [f64; 2];
-    let mut ref_z0: [f64; 2] = stable_inverter.output(0.0);
-    let mut u_ff: [f64; SLOOP_NUM] = [0.0; SLOOP_NUM];
-    for i in 0..SAMPLES {
-        ref_z1 = stable_inverter.output(t + TM);
-        let u_ff_block: [f64; PLANT_ORDER] = b_ssr.calculate_input(&ref_z0, &ref_z1);
-        for j in 0..PLANT_ORDER {
-            u_ff[PLANT_ORDER * i + j] = u_ff_block[j];
-        }
-        ref_z0 = ref_z1;
-        t += TM;
-    }
-
     //Simulator
-    t = 0.0;
+    let mut controller = PerfectTrackingController::new(&c_ssr, TS)
+        .unwrap()
+        .set_initial_reference(&stable_inverter.output(0.0));
+    if STATE_FEEDBACK {
+        controller = controller.set_deadbeat_feedback();
+    }
     let mut c_plant = continuous::Plant::new(&c_ssr, TP);
-    for i in 0..SLOOP_NUM {
-        for _ in 0..PLOOP_NUM {
-            c_plant.update(u_ff[i]);
+    for _ in 0..SAMPLES {
+        controller.calc(&stable_inverter.output(t + TM), &c_plant.state());
+        for _ in 0..PLANT_ORDER {
+            let u: f64 = controller.hold();
+            for _ in 0..PLOOP_NUM {
+                c_plant.update(u);
+            }
+            t += TS;
+
+            data_storage.add([t, reference(t)[0], c_plant.y]);
         }
-        t += TS;
-        
-        data_storage.add([t, reference(t)[0], c_plant.y]);
-    } 
+    }
 
     data_storage.write_file()?;
 
@@ -0,0 +1,27 @@
+reset
+
+data = "../data/estimator.csv"
+
+set datafile separator ","
+set grid
+
+set multiplot layout 2,1
+
+p data u 1:2 w l ti "q0 mekf",\
+  data u 1:3 w l ti "q1 mekf",\
+  data u 1:6 w l ti "q0 madgwick",\
+  data u 1:7 w l ti "q1 madgwick",\
+  data u 1:10 w l ti "q0 mahony",\
+  data u 1:11 w l ti "q1 mahony"
+
+p data u 1:14 w l ti "bias0 mekf",\
+  data u 1:15 w l ti "bias1 mekf",\
+  data u 1:16 w l ti "bias2 mekf",\
+  data u 1:17 w l ti "bias0 madgwick",\
+  data u 1:18 w l ti "bias1 madgwick",\
+  data u 1:19 w l ti "bias2 madgwick",\
+  data u 1:20 w l ti "bias0 mahony",\
+  data u 1:21 w l ti "bias1 mahony",\
+  data u 1:22 w l ti "bias2 mahony"
+
+unset multiplot
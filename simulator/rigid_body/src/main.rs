@@ -4,6 +4,7 @@
 use std::error::Error;
 
 use digitalservo::data_storage::DataStorage;
+use digitalservo::observer::attitude_estimator;
 use digitalservo::observer::disturbance_observer as dob;
 use digitalservo::observer::quaternion_observer;
 use digitalservo::plant::rigid_body;
@@ -20,8 +21,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     const DATAILE_SEPARATOR: &str = ",";
     let output_filename_quate: String = format!("data/quaternion.csv");
     let output_filename_omega: String = format!("data/omega.csv");
+    let output_filename_estimator: String = format!("data/estimator.csv");
     let mut data_storage_quate = DataStorage::new(output_filename_quate, DATAILE_SEPARATOR, SLOOP_NUM);
     let mut data_storage_omega = DataStorage::new(output_filename_omega, DATAILE_SEPARATOR, SLOOP_NUM);
+    let mut data_storage_estimator = DataStorage::new(output_filename_estimator, DATAILE_SEPARATOR, SLOOP_NUM);
 
     //rigid body
     let jb: [f64; 3] = [1.0, 2.0, 5.0];
@@ -48,6 +51,21 @@ fn main() -> Result<(), Box<dyn Error>> {
         .set_gain(gain_quaternion, gain_bias)
         .set_init_quartenion(&[1.0, 0.0, 0.0, 0.0]);
 
+    //alternative attitude estimators (for comparison only)
+    let mut mekf = attitude_estimator::MultiplicativeEKF::new(TS)
+        .set_mb(&mb)
+        .set_noise(0.01, 0.01, 0.1, 0.1)
+        .set_init_covariance(0.1, 0.3)
+        .set_init_quartenion(&[1.0, 0.0, 0.0, 0.0]);
+    let mut madgwick = attitude_estimator::MadgwickFilter::new(TS)
+        .set_mb(&mb)
+        .set_gain(2.0, 5.0)
+        .set_init_quartenion(&[1.0, 0.0, 0.0, 0.0]);
+    let mut mahony = attitude_estimator::MahonyFilter::new(TS)
+        .set_mb(&mb)
+        .set_gain(10.0, 30.0)
+        .set_init_quartenion(&[1.0, 0.0, 0.0, 0.0]);
+
     //command generator
     let rotation_axis: [f64; 3] = [1.0, 4.0, 5.0];
     let rotation_axis_norm: f64 =
@@ -109,6 +127,9 @@ fn main() -> Result<(), Box<dyn Error>> {
         /* quaternion observer */
         let est = quaternion_observer.estimate(&omega_sense, &force, &acc_b_sense, &geomag_b_sense);
         omega_bias_hat = quaternion_observer.omega_bias_hat.data;
+        let est_mekf = mekf.estimate(&omega_sense, &force, &acc_b_sense, &geomag_b_sense);
+        let est_madgwick = madgwick.estimate(&omega_sense, &force, &acc_b_sense, &geomag_b_sense);
+        let est_mahony = mahony.estimate(&omega_sense, &force, &acc_b_sense, &geomag_b_sense);
 
         /* disturbance observer */
         for i in 0..rigid_body::JOINTSPACE_DIM {
@@ -170,10 +191,37 @@ fn main() -> Result<(), Box<dyn Error>> {
             omega_bias_hat[1],
             omega_bias_hat[2],
         ]);
+
+        //Logging (attitude estimators)
+        data_storage_estimator.add([
+            t,
+            est_mekf.q[0],
+            est_mekf.q[1],
+            est_mekf.q[2],
+            est_mekf.q[3],
+            est_madgwick.q[0],
+            est_madgwick.q[1],
+            est_madgwick.q[2],
+            est_madgwick.q[3],
+            est_mahony.q[0],
+            est_mahony.q[1],
+            est_mahony.q[2],
+            est_mahony.q[3],
+            mekf.omega_bias_hat[0],
+            mekf.omega_bias_hat[1],
+            mekf.omega_bias_hat[2],
+            madgwick.omega_bias_hat[0],
+            madgwick.omega_bias_hat[1],
+            madgwick.omega_bias_hat[2],
+            mahony.omega_bias_hat[0],
+            mahony.omega_bias_hat[1],
+            mahony.omega_bias_hat[2],
+        ]);
     }
 
     data_storage_quate.write_file()?;
     data_storage_omega.write_file()?;
+    data_storage_estimator.write_file()?;
 
     Ok(())
 }
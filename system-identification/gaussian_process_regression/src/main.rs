@@ -51,7 +51,7 @@ fn main() {
     for i in 0..DATA_MAPPING {
         let x: f64 = gpr.x_min + dx * i as f64;
         let y: f64 = f(x);
-        let predict = gpr.predict(x);
+        let predict = gpr.predict(x).unwrap();
         data_storage_map.add([x, y, predict.mean, predict.stdev]);
     }
 